@@ -47,9 +47,9 @@ pub struct MyContactListener;
 impl jolt::ContactListener for MyContactListener {
     fn on_contact_validate(
         &self,
-        _body1: &jolt::Body,
-        _body2: &jolt::Body,
-        _base_offset: mint::Vector3<f32>,
+        _body1: jolt::BodyRef,
+        _body2: jolt::BodyRef,
+        _base_offset: mint::Vector3<jolt::Real>,
         _collision_result: &jolt::CollideShapeResult,
     ) -> jolt::ValidateResult {
         println!("Contact validate callback");
@@ -58,8 +58,8 @@ impl jolt::ContactListener for MyContactListener {
 
     fn on_contact_added(
         &self,
-        _body1: &jolt::Body,
-        _body2: &jolt::Body,
+        _body1: jolt::BodyRef,
+        _body2: jolt::BodyRef,
         _manifold: &jolt::ContactManifold,
         _io_settings: &mut jolt::ContactSettings,
     ) {
@@ -68,8 +68,8 @@ impl jolt::ContactListener for MyContactListener {
 
     fn on_contact_persisted(
         &self,
-        _body1: &jolt::Body,
-        _body2: &jolt::Body,
+        _body1: jolt::BodyRef,
+        _body2: jolt::BodyRef,
         _manifold: &jolt::ContactManifold,
         _io_settings: &mut jolt::ContactSettings,
     ) {
@@ -113,12 +113,11 @@ const MAX_CONTACT_CONSTRAINTS: u32 = 1024;
 fn main() {
     println!("Features: {:?}", jolt::get_features());
 
-    jolt::register_default_allocator();
-    jolt::create_factory();
-    jolt::register_types();
+    let runtime = jolt::JoltRuntime::init();
 
-    let mut temp_allocator = jolt::TempAllocator::create(10 * 1024 * 1024);
+    let mut temp_allocator = jolt::TempAllocator::create(&runtime, 10 * 1024 * 1024);
     let mut job_system = jolt::JobSystem::create(
+        &runtime,
         jolt::JobSystem::MAX_PHYSICS_JOBS,
         jolt::JobSystem::MAX_PHYSICS_BARRIERS,
         (num_cpus::get() - 1).clamp(1, 16) as _,
@@ -128,7 +127,8 @@ fn main() {
     let object_vs_broadphase_layer_filter = ObjectVsBroadPhaseLayerFilterImpl;
     let object_vs_object_layer_filter = OLayerPairInterfaceImpl;
 
-    let physics_system = jolt::PhysicsSystem::create(
+    let mut physics_system = jolt::PhysicsSystem::create(
+        &runtime,
         MAX_BODIES,
         NUM_BODY_MUTEXES,
         MAX_BODY_PAIRS,
@@ -138,8 +138,6 @@ fn main() {
         Box::new(object_vs_object_layer_filter),
     );
 
-    let body_interface = physics_system.body_interface();
-
     // A body activation listener gets notified when bodies activate and go to sleep
     // Note that this is called from a job so whatever you do here needs to be thread safe.
     // Registering one is entirely optional.
@@ -152,20 +150,23 @@ fn main() {
     let contact_listener = MyContactListener;
     physics_system.set_contact_listener(Box::new(contact_listener));
 
+    // The main way to interact with the bodies in the physics system is through the body interface.
+    let body_interface = physics_system.body_interface();
+
     // Next we can create a rigid body to serve as the floor, we make a large box
     // Create the settings for the collision volume (the shape).
     // Note that for simple shapes (like boxes) you can also directly construct a BoxShape.
-    let floor_shape_settings = jolt::BoxShapeSettings::new(Vec3::new(100.0, 1.0, 100.0));
+    let floor_shape_settings = jolt::BoxShapeSettings::new(&runtime, Vec3::new(100.0, 1.0, 100.0));
 
     // Create the shape
     let floor_shape = floor_shape_settings
-        .create_shape()
+        .create_shape(&runtime)
         .expect("Failed to create floor shape");
 
     // Create the settings for the body itself. Note that here you can also set other properties like the restitution / friction.
     let floor_settings = jolt::BodyCreationSettings::new(
         floor_shape,
-        vec3(0.0, -1.0, 0.0),
+        [0.0, -1.0, 0.0],
         Quat::IDENTITY,
         jolt::MotionType::Static,
         OLAYER_NON_MOVING,
@@ -174,31 +175,39 @@ fn main() {
     // Create the actual rigid body
     let floor = body_interface
         .create_body(&floor_settings)
-        .expect("Failed to create body"); // Note that if we run out of bodies this can return None
+        .expect("Failed to create body"); // Note that if we run out of bodies this can return an error
 
     // Add it to the world
-    body_interface.add_body(unsafe { (*floor).id }, jolt::Activation::DontActivate);
+    body_interface.add_body(floor, jolt::Activation::DontActivate);
 
     // Now create a dynamic body to bounce on the floor
     // Note that this uses the shorthand version of creating and adding a body to the world
     let sphere_settings = jolt::BodyCreationSettings::new(
-        jolt::SphereShapeSettings::new(0.5)
-            .create_shape()
+        jolt::SphereShapeSettings::new(&runtime, 0.5)
+            .create_shape(&runtime)
             .expect("Failed to create sphere shape"),
-        vec3(0.0, 2.0, 0.0),
+        [0.0, 2.0, 0.0],
         Quat::IDENTITY,
         jolt::MotionType::Dynamic,
         OLAYER_MOVING,
     );
-    let sphere_id =
-        body_interface.create_and_add_body(&sphere_settings, jolt::Activation::Activate);
+    let sphere_id = body_interface
+        .create_and_add_body(&sphere_settings, jolt::Activation::Activate)
+        .expect("Failed to create sphere");
 
     // Now you can interact with the dynamic body, in this case we're going to give it a velocity.
     // (note that if we had used CreateBody then we could have set the velocity straight on the body before adding it to the physics system)
     body_interface.set_linear_velocity(sphere_id, vec3(0.0, -5.0, 0.0));
 
     let mut step = 0;
-    while body_interface.is_active(sphere_id) {
+    loop {
+        // Stepping needs exclusive access to the physics system, so the body interface can't be
+        // held on to across updates.
+        let body_interface = physics_system.body_interface();
+        if !body_interface.is_active(sphere_id) {
+            break;
+        }
+
         step += 1;
 
         let position = body_interface.center_of_mass_position(sphere_id);
@@ -208,15 +217,19 @@ fn main() {
         // If you take larger steps than 1 / 60th of a second you need to do multiple collision steps in order to keep the simulation stable. Do 1 collision step per 1 / 60th of a second (round up).
         const COLLISION_STEPS: i32 = 1;
 
-        physics_system.update(
-            1. / 60.,
-            COLLISION_STEPS,
-            &mut temp_allocator,
-            &mut job_system,
-        );
+        physics_system
+            .update(
+                1. / 60.,
+                COLLISION_STEPS,
+                &mut temp_allocator,
+                &mut job_system,
+            )
+            .expect("Physics update failed");
         physics_system.optimize_broad_phase();
     }
 
-    let pos = body_interface.center_of_mass_position(sphere_id);
+    let pos = physics_system
+        .body_interface()
+        .center_of_mass_position(sphere_id);
     println!("Final Position = {pos:?}");
 }
@@ -14,12 +14,11 @@ pub fn main() {
     })
     .unwrap();
 
-    jolt::register_default_allocator();
-    jolt::create_factory();
-    jolt::register_types();
+    let runtime = jolt::JoltRuntime::init();
 
     let mut temp_allocator = jolt::TempAllocator::create(10 * 1024 * 1024);
     let mut job_system = jolt::JobSystem::create(
+        &runtime,
         jolt::JobSystem::MAX_PHYSICS_JOBS,
         jolt::JobSystem::MAX_PHYSICS_BARRIERS,
         (std::thread::available_parallelism()
@@ -34,6 +33,7 @@ pub fn main() {
     let object_vs_object_layer_filter = OLayerPairInterfaceImpl;
 
     let physics_system = jolt::PhysicsSystem::create(
+        &runtime,
         10240,
         0,
         65536,
@@ -70,7 +70,9 @@ pub fn main() {
         OLAYER_NON_MOVING,
     );
 
-    body_interface.create_and_add_body(&floor_settings, jolt::Activation::DontActivate);
+    body_interface
+        .create_and_add_body(&floor_settings, jolt::Activation::DontActivate)
+        .expect("Failed to create wall");
 
     // Left plane
     let floor_settings = jolt::BodyCreationSettings::new(
@@ -81,7 +83,9 @@ pub fn main() {
         OLAYER_NON_MOVING,
     );
 
-    body_interface.create_and_add_body(&floor_settings, jolt::Activation::DontActivate);
+    body_interface
+        .create_and_add_body(&floor_settings, jolt::Activation::DontActivate)
+        .expect("Failed to create wall");
 
     // Right plane
     let floor_settings = jolt::BodyCreationSettings::new(
@@ -92,7 +96,9 @@ pub fn main() {
         OLAYER_NON_MOVING,
     );
 
-    body_interface.create_and_add_body(&floor_settings, jolt::Activation::DontActivate);
+    body_interface
+        .create_and_add_body(&floor_settings, jolt::Activation::DontActivate)
+        .expect("Failed to create wall");
 
     // Front plane
     let floor_settings = jolt::BodyCreationSettings::new(
@@ -103,7 +109,9 @@ pub fn main() {
         OLAYER_NON_MOVING,
     );
 
-    body_interface.create_and_add_body(&floor_settings, jolt::Activation::DontActivate);
+    body_interface
+        .create_and_add_body(&floor_settings, jolt::Activation::DontActivate)
+        .expect("Failed to create wall");
 
     // Back plane
     let floor_settings = jolt::BodyCreationSettings::new(
@@ -114,7 +122,9 @@ pub fn main() {
         OLAYER_NON_MOVING,
     );
 
-    body_interface.create_and_add_body(&floor_settings, jolt::Activation::DontActivate);
+    body_interface
+        .create_and_add_body(&floor_settings, jolt::Activation::DontActivate)
+        .expect("Failed to create wall");
 
     let light0 = DirectionalLight::new(&context, 1.0, Srgba::WHITE, &vec3(0.0, -0.5, -0.5));
     let light1 = DirectionalLight::new(&context, 1.0, Srgba::WHITE, &vec3(0.0, 0.5, 0.5));
@@ -155,7 +165,8 @@ pub fn main() {
                     OLAYER_MOVING,
                 );
                 let sphere_id = body_interface
-                    .create_and_add_body(&sphere_settings, jolt::Activation::Activate);
+                    .create_and_add_body(&sphere_settings, jolt::Activation::Activate)
+                    .expect("Failed to create sphere");
                 body_interface.set_linear_velocity(sphere_id, [0.0, -10.0, 0.0]);
 
                 spheres.push((sphere_id, (random::<[u8; 3]>())));
@@ -2,6 +2,9 @@
 
 #include <assert.h>
 #include <stddef.h>
+#include <string.h>
+#include <stdarg.h>
+#include <stdio.h>
 
 #include <Jolt/Jolt.h>
 #include <Jolt/RegisterTypes.h>
@@ -9,11 +12,14 @@
 #include <Jolt/Core/TempAllocator.h>
 #include <Jolt/Core/Memory.h>
 #include <Jolt/Core/JobSystemThreadPool.h>
+#include <Jolt/Core/JobSystemSingleThreaded.h>
+#include <Jolt/Core/JobSystemWithBarrier.h>
 #include <Jolt/Physics/PhysicsSettings.h>
 #include <Jolt/Physics/PhysicsSystem.h>
 #include <Jolt/Physics/EPhysicsUpdateError.h>
 #include <Jolt/Physics/Collision/NarrowPhaseQuery.h>
 #include <Jolt/Physics/Collision/CollideShape.h>
+#include <Jolt/Physics/Collision/GroupFilterTable.h>
 #include <Jolt/Physics/Collision/Shape/BoxShape.h>
 #include <Jolt/Physics/Collision/Shape/SphereShape.h>
 #include <Jolt/Physics/Collision/Shape/TriangleShape.h>
@@ -29,6 +35,7 @@
 #include <Jolt/Physics/Collision/Shape/StaticCompoundShape.h>
 #include <Jolt/Physics/Collision/Shape/MutableCompoundShape.h>
 #include <Jolt/Physics/Collision/PhysicsMaterial.h>
+#include <Jolt/Physics/Collision/PhysicsMaterialSimple.h>
 #include <Jolt/Physics/Constraints/FixedConstraint.h>
 #include <Jolt/Physics/Body/BodyCreationSettings.h>
 #include <Jolt/Physics/Body/BodyActivationListener.h>
@@ -61,6 +68,10 @@ JPH_SUPPRESS_WARNINGS
 
 FN(toJph)(JPC_BodyID in) { return JPH::BodyID(in); }
 FN(toJpc)(JPH::BodyID in) { return in.GetIndexAndSequenceNumber(); }
+static_assert(sizeof(JPH::BodyID) == sizeof(JPC_BodyID));
+// JPC_BodyID and JPC_SubShapeID are both uint32_t, so arrays of body ids can't overload toJph
+FN(toJphBodyIDs)(const JPC_BodyID *in) { return reinterpret_cast<const JPH::BodyID *>(in); }
+FN(toJphBodyIDs)(JPC_BodyID *in) { return reinterpret_cast<JPH::BodyID *>(in); }
 
 FN(toJpc)(const JPH::Body *in) { assert(in); return reinterpret_cast<const JPC_Body *>(in); }
 FN(toJph)(const JPC_Body *in) { assert(in); return reinterpret_cast<const JPH::Body *>(in); }
@@ -257,6 +268,10 @@ FN(toJph)(const JPC_CollisionGroup *in) { assert(in); return reinterpret_cast<co
 FN(toJpc)(const JPH::CollisionGroup *in) { assert(in); return reinterpret_cast<const JPC_CollisionGroup *>(in); }
 FN(toJpc)(JPH::CollisionGroup *in) { assert(in); return reinterpret_cast<JPC_CollisionGroup *>(in); }
 
+FN(toJph)(const JPC_GroupFilter *in) { assert(in); return reinterpret_cast<const JPH::GroupFilter *>(in); }
+FN(toJph)(JPC_GroupFilter *in) { assert(in); return reinterpret_cast<JPH::GroupFilter *>(in); }
+FN(toJpc)(JPH::GroupFilter *in) { assert(in); return reinterpret_cast<JPC_GroupFilter *>(in); }
+
 FN(toJph)(const JPC_SubShapeID *in) { assert(in); return reinterpret_cast<const JPH::SubShapeID *>(in); }
 
 FN(toJph)(const JPC_BodyLockInterface *in) {
@@ -466,6 +481,30 @@ JPC_SetAssertFailedHandler(JPC_AssertFailedFunction in_handler) {
 #endif
 }
 
+static JPC_TraceFunction sTraceHandler = nullptr;
+
+// Jolt's trace function is variadic, so format the message here and hand the C callback a plain string.
+static void
+TraceImpl(const char *in_fmt, ...)
+{
+    if (sTraceHandler == nullptr)
+        return;
+
+    char buffer[1024];
+    va_list list;
+    va_start(list, in_fmt);
+    vsnprintf(buffer, sizeof(buffer), in_fmt, list);
+    va_end(list);
+
+    sTraceHandler(buffer);
+}
+
+JPC_API void
+JPC_SetTraceHandler(JPC_TraceFunction in_handler) {
+    sTraceHandler = in_handler;
+    JPH::Trace = TraceImpl;
+}
+
 //--------------------------------------------------------------------------------------------------
 JPC_API void
 JPC_RegisterDefaultAllocator(void)
@@ -473,6 +512,24 @@ JPC_RegisterDefaultAllocator(void)
     JPH::RegisterDefaultAllocator();
 }
 //--------------------------------------------------------------------------------------------------
+#if !defined(JPH_DISABLE_CUSTOM_ALLOCATOR) && JPH_VERSION_MAJOR >= 5
+static JPC_AllocateFunction sCustomAllocate = nullptr;
+static JPC_FreeFunction sCustomFree = nullptr;
+
+// The C API doesn't expose a reallocate callback, so build one on top of the custom allocate/free pair.
+static void *
+CustomReallocate(void *in_block, size_t in_old_size, size_t in_new_size)
+{
+    void *new_block = sCustomAllocate(in_new_size);
+    if (new_block != nullptr && in_block != nullptr)
+    {
+        memcpy(new_block, in_block, in_old_size < in_new_size ? in_old_size : in_new_size);
+        sCustomFree(in_block);
+    }
+    return new_block;
+}
+#endif
+//--------------------------------------------------------------------------------------------------
 JPC_API void
 JPC_RegisterCustomAllocator(JPC_AllocateFunction in_alloc,
                             JPC_FreeFunction in_free,
@@ -484,6 +541,11 @@ JPC_RegisterCustomAllocator(JPC_AllocateFunction in_alloc,
     JPH::Free = in_free;
     JPH::AlignedAllocate = in_aligned_alloc;
     JPH::AlignedFree = in_aligned_free;
+#if JPH_VERSION_MAJOR >= 5
+    sCustomAllocate = in_alloc;
+    sCustomFree = in_free;
+    JPH::Reallocate = CustomReallocate;
+#endif
 #endif
 }
 //--------------------------------------------------------------------------------------------------
@@ -548,11 +610,104 @@ JPC_BodyCreationSettings_Set(JPC_BodyCreationSettings *out_settings,
 // JPC_TempAllocator
 //
 //--------------------------------------------------------------------------------------------------
+// Wraps an arena to keep track of its usage. Depending on the strategy, allocations that don't fit the arena either
+// abort (like JPH::TempAllocatorImpl does), go to Jolt's regular allocator or make the arena grow on the next
+// ResetStats().
+class TempAllocatorWithStats final : public JPH::TempAllocator
+{
+public:
+    TempAllocatorWithStats(JPC_TempAllocatorStrategy in_strategy, uint32_t in_size)
+        : strategy(in_strategy),
+          // FIXED keeps an arena even when it's empty, so running out aborts like TempAllocatorImpl does
+          arena(in_strategy == JPC_TEMP_ALLOCATOR_FIXED || in_size > 0 ? new JPH::TempAllocatorImpl(in_size)
+                                                                        : nullptr),
+          capacity(in_size)
+    {
+    }
+
+    ~TempAllocatorWithStats() override
+    {
+        delete arena;
+    }
+
+    void *Allocate(JPH::uint inSize) override
+    {
+        if (inSize == 0)
+            return nullptr;
+
+        const uint32_t size = JPH::AlignUp(inSize, JPH_RVECTOR_ALIGNMENT);
+        void *block;
+        if (strategy == JPC_TEMP_ALLOCATOR_FIXED || (arena != nullptr && arena->CanAllocate(size)))
+        {
+            block = arena->Allocate(size);
+        }
+        else
+        {
+            block = JPH::AlignedAllocate(size, JPH_RVECTOR_ALIGNMENT);
+            fallback_allocations++;
+        }
+
+        usage += size;
+        if (usage > high_water_mark)
+            high_water_mark = usage;
+        return block;
+    }
+
+    void Free(void *inAddress, JPH::uint inSize) override
+    {
+        if (inAddress == nullptr)
+            return;
+
+        const uint32_t size = JPH::AlignUp(inSize, JPH_RVECTOR_ALIGNMENT);
+        if (arena != nullptr && arena->OwnsMemory(inAddress))
+            arena->Free(inAddress, size);
+        else
+            JPH::AlignedFree(inAddress);
+        usage -= size;
+    }
+
+    void GetStats(JPC_TempAllocatorStats *out_stats) const
+    {
+        out_stats->capacity = capacity;
+        out_stats->usage = usage;
+        out_stats->high_water_mark = high_water_mark;
+        out_stats->fallback_allocations = fallback_allocations;
+    }
+
+    void ResetStats()
+    {
+        // Only swap the arena for one that fits the peak usage while nothing is allocated from it
+        if (strategy == JPC_TEMP_ALLOCATOR_GROWABLE && usage == 0 && high_water_mark > capacity)
+        {
+            delete arena;
+            capacity = high_water_mark;
+            arena = new JPH::TempAllocatorImpl(capacity);
+        }
+
+        high_water_mark = usage;
+        fallback_allocations = 0;
+    }
+
+private:
+    JPC_TempAllocatorStrategy strategy;
+    JPH::TempAllocatorImpl *arena;
+    uint32_t capacity;
+    uint32_t usage = 0;
+    uint32_t high_water_mark = 0;
+    uint32_t fallback_allocations = 0;
+};
+//--------------------------------------------------------------------------------------------------
 JPC_API JPC_TempAllocator *
 JPC_TempAllocator_Create(uint32_t in_size)
 {
-    auto impl = new JPH::TempAllocatorImpl(in_size);
-    return reinterpret_cast<JPC_TempAllocator *>(impl);
+    return JPC_TempAllocator_CreateWithStrategy(JPC_TEMP_ALLOCATOR_FIXED, in_size);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API JPC_TempAllocator *
+JPC_TempAllocator_CreateWithStrategy(JPC_TempAllocatorStrategy in_strategy, uint32_t in_size)
+{
+    auto impl = new TempAllocatorWithStats(in_strategy, in_size);
+    return reinterpret_cast<JPC_TempAllocator *>(static_cast<JPH::TempAllocator *>(impl));
 }
 //--------------------------------------------------------------------------------------------------
 JPC_API void
@@ -562,10 +717,104 @@ JPC_TempAllocator_Destroy(JPC_TempAllocator *in_allocator)
     delete reinterpret_cast<JPH::TempAllocator *>(in_allocator);
 }
 //--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_TempAllocator_GetStats(const JPC_TempAllocator *in_allocator, JPC_TempAllocatorStats *out_stats)
+{
+    assert(in_allocator != nullptr && out_stats != nullptr);
+    static_cast<const TempAllocatorWithStats *>(reinterpret_cast<const JPH::TempAllocator *>(in_allocator))
+        ->GetStats(out_stats);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_TempAllocator_ResetStats(JPC_TempAllocator *in_allocator)
+{
+    assert(in_allocator != nullptr);
+    static_cast<TempAllocatorWithStats *>(reinterpret_cast<JPH::TempAllocator *>(in_allocator))->ResetStats();
+}
+//--------------------------------------------------------------------------------------------------
 //
 // JPC_JobSystem
 //
 //--------------------------------------------------------------------------------------------------
+class CustomJobSystem final : public JPH::JobSystemWithBarrier
+{
+public:
+    CustomJobSystem(uint32_t in_max_barriers,
+                    uint32_t in_max_concurrency,
+                    JPC_QueueJobFunction in_queue_job,
+                    void *in_user_data)
+        : JPH::JobSystemWithBarrier(in_max_barriers),
+          max_concurrency(in_max_concurrency),
+          queue_job(in_queue_job),
+          user_data(in_user_data)
+    {
+    }
+
+    int GetMaxConcurrency() const override
+    {
+        return static_cast<int>(max_concurrency);
+    }
+
+    JobHandle CreateJob(const char *inName,
+                        JPH::ColorArg inColor,
+                        const JobFunction &inJobFunction,
+                        JPH::uint32 inNumDependencies = 0) override
+    {
+        Job *job = new Job(inName, inColor, this, inJobFunction, inNumDependencies);
+        JobHandle handle(job);
+        if (inNumDependencies == 0)
+            QueueJob(job);
+        return handle;
+    }
+
+    static void Execute(JPC_Job *in_job)
+    {
+        reinterpret_cast<Job *>(in_job)->Execute();
+    }
+
+    static void Release(JPC_Job *in_job)
+    {
+        Job *job = reinterpret_cast<Job *>(in_job);
+        auto job_system = static_cast<CustomJobSystem *>(job->GetJobSystem());
+        job->Release();
+        job_system->ReleaseRef();
+    }
+
+    // The owner and every queued job hold a reference, so jobs the executor releases after
+    // JPC_JobSystem_DestroyCustom() can still be freed
+    void ReleaseRef()
+    {
+        if (num_refs.fetch_sub(1, std::memory_order_acq_rel) == 1)
+            delete this;
+    }
+
+protected:
+    void QueueJob(Job *inJob) override
+    {
+        // Keeps the job alive until the executor calls JPC_Job_Release()
+        inJob->AddRef();
+        num_refs.fetch_add(1, std::memory_order_relaxed);
+        queue_job(user_data, reinterpret_cast<JPC_Job *>(inJob));
+    }
+
+    void QueueJobs(Job **inJobs, JPH::uint inNumJobs) override
+    {
+        for (JPH::uint i = 0; i < inNumJobs; ++i)
+            QueueJob(inJobs[i]);
+    }
+
+    void FreeJob(Job *inJob) override
+    {
+        delete inJob;
+    }
+
+private:
+    uint32_t max_concurrency;
+    JPC_QueueJobFunction queue_job;
+    void *user_data;
+    std::atomic<uint32_t> num_refs = 1;
+};
+//--------------------------------------------------------------------------------------------------
 JPC_API JPC_JobSystem *
 JPC_JobSystem_Create(uint32_t in_max_jobs, uint32_t in_max_barriers, int in_num_threads)
 {
@@ -573,11 +822,50 @@ JPC_JobSystem_Create(uint32_t in_max_jobs, uint32_t in_max_barriers, int in_num_
     return reinterpret_cast<JPC_JobSystem *>(job_system);
 }
 //--------------------------------------------------------------------------------------------------
+JPC_API JPC_JobSystem *
+JPC_JobSystem_CreateSingleThreaded(uint32_t in_max_jobs)
+{
+    auto job_system = new JPH::JobSystemSingleThreaded(in_max_jobs);
+    return reinterpret_cast<JPC_JobSystem *>(static_cast<JPH::JobSystem *>(job_system));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API JPC_JobSystem *
+JPC_JobSystem_CreateCustom(uint32_t in_max_barriers,
+                           uint32_t in_max_concurrency,
+                           JPC_QueueJobFunction in_queue_job,
+                           void *in_user_data)
+{
+    assert(in_queue_job != nullptr);
+    auto job_system = new CustomJobSystem(in_max_barriers, in_max_concurrency, in_queue_job, in_user_data);
+    return reinterpret_cast<JPC_JobSystem *>(static_cast<JPH::JobSystem *>(job_system));
+}
+//--------------------------------------------------------------------------------------------------
 JPC_API void
 JPC_JobSystem_Destroy(JPC_JobSystem *in_job_system)
 {
     assert(in_job_system != nullptr);
-    delete reinterpret_cast<JPH::JobSystemThreadPool *>(in_job_system);
+    delete reinterpret_cast<JPH::JobSystem *>(in_job_system);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_JobSystem_DestroyCustom(JPC_JobSystem *in_job_system)
+{
+    assert(in_job_system != nullptr);
+    static_cast<CustomJobSystem *>(reinterpret_cast<JPH::JobSystem *>(in_job_system))->ReleaseRef();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_Job_Execute(JPC_Job *in_job)
+{
+    assert(in_job != nullptr);
+    CustomJobSystem::Execute(in_job);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_Job_Release(JPC_Job *in_job)
+{
+    assert(in_job != nullptr);
+    CustomJobSystem::Release(in_job);
 }
 //--------------------------------------------------------------------------------------------------
 //
@@ -1189,6 +1477,43 @@ JPC_NarrowPhaseQuery_CastRay(const JPC_NarrowPhaseQuery *in_query,
 }
 //--------------------------------------------------------------------------------------------------
 //
+// JPC_PhysicsMaterial
+//
+//--------------------------------------------------------------------------------------------------
+JPC_API JPC_PhysicsMaterial *
+JPC_PhysicsMaterial_Create(const char *in_debug_name, uint32_t in_debug_color)
+{
+    assert(in_debug_name != nullptr);
+    auto material = new JPH::PhysicsMaterialSimple(in_debug_name, JPH::Color(in_debug_color));
+    material->AddRef();
+    return reinterpret_cast<JPC_PhysicsMaterial *>(static_cast<JPH::PhysicsMaterial *>(material));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_PhysicsMaterial_AddRef(const JPC_PhysicsMaterial *in_material)
+{
+    toJph(in_material)->AddRef();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_PhysicsMaterial_Release(const JPC_PhysicsMaterial *in_material)
+{
+    toJph(in_material)->Release();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API const char *
+JPC_PhysicsMaterial_GetDebugName(const JPC_PhysicsMaterial *in_material)
+{
+    return toJph(in_material)->GetDebugName();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API uint32_t
+JPC_PhysicsMaterial_GetDebugColor(const JPC_PhysicsMaterial *in_material)
+{
+    return toJph(in_material)->GetDebugColor().GetUInt32();
+}
+//--------------------------------------------------------------------------------------------------
+//
 // JPC_ShapeSettings
 //
 //--------------------------------------------------------------------------------------------------
@@ -1638,6 +1963,53 @@ JPC_HeightFieldShapeSettings_SetBitsPerSample(JPC_HeightFieldShapeSettings *in_s
     toJph(in_settings)->mBitsPerSample = in_num_bits;
 }
 //--------------------------------------------------------------------------------------------------
+JPC_API float
+JPC_HeightFieldShapeSettings_GetMinHeightValue(const JPC_HeightFieldShapeSettings *in_settings)
+{
+    return toJph(in_settings)->mMinHeightValue;
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMinHeightValue(JPC_HeightFieldShapeSettings *in_settings, float in_min_height_value)
+{
+    toJph(in_settings)->mMinHeightValue = in_min_height_value;
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API float
+JPC_HeightFieldShapeSettings_GetMaxHeightValue(const JPC_HeightFieldShapeSettings *in_settings)
+{
+    return toJph(in_settings)->mMaxHeightValue;
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMaxHeightValue(JPC_HeightFieldShapeSettings *in_settings, float in_max_height_value)
+{
+    toJph(in_settings)->mMaxHeightValue = in_max_height_value;
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMaterials(JPC_HeightFieldShapeSettings *in_settings,
+                                          const JPC_PhysicsMaterial *const *in_materials,
+                                          uint32_t in_num_materials)
+{
+    assert(in_materials != nullptr || in_num_materials == 0);
+    JPH::PhysicsMaterialList &materials = toJph(in_settings)->mMaterials;
+    materials.clear();
+    materials.reserve(in_num_materials);
+    for (uint32_t i = 0; i < in_num_materials; ++i)
+        materials.push_back(toJph(in_materials[i]));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMaterialIndices(JPC_HeightFieldShapeSettings *in_settings,
+                                                const uint8_t *in_material_indices,
+                                                uint32_t in_num_material_indices)
+{
+    assert(in_material_indices != nullptr || in_num_material_indices == 0);
+    toJph(in_settings)->mMaterialIndices.assign(
+        in_material_indices, in_material_indices + in_num_material_indices);
+}
+//--------------------------------------------------------------------------------------------------
 //
 // JPC_MeshShapeSettings (-> JPC_ShapeSettings)
 //
@@ -1702,10 +2074,10 @@ JPC_MeshShapeSettings_Sanitize(JPC_MeshShapeSettings *in_settings)
 //--------------------------------------------------------------------------------------------------
 JPC_API JPC_DecoratedShapeSettings *
 JPC_RotatedTranslatedShapeSettings_Create(const JPC_ShapeSettings *in_inner_shape_settings,
-                                          const JPC_Real in_rotated[4],
-                                          const JPC_Real in_translated[3])
+                                          const float in_rotated[4],
+                                          const float in_translated[3])
 {
-    auto settings = new JPH::RotatedTranslatedShapeSettings(loadRVec3(in_translated),
+    auto settings = new JPH::RotatedTranslatedShapeSettings(loadVec3(in_translated),
                                                             JPH::Quat(loadVec4(in_rotated)),
                                                             toJph(in_inner_shape_settings));
     settings->AddRef();
@@ -1714,23 +2086,41 @@ JPC_RotatedTranslatedShapeSettings_Create(const JPC_ShapeSettings *in_inner_shap
 //--------------------------------------------------------------------------------------------------
 JPC_API JPC_DecoratedShapeSettings *
 JPC_ScaledShapeSettings_Create(const JPC_ShapeSettings *in_inner_shape_settings,
-                               const JPC_Real in_scale[3])
+                               const float in_scale[3])
 {
-    auto settings = new JPH::ScaledShapeSettings(toJph(in_inner_shape_settings), loadRVec3(in_scale));
+    auto settings = new JPH::ScaledShapeSettings(toJph(in_inner_shape_settings), loadVec3(in_scale));
     settings->AddRef();
     return toJpc(settings);
 }
 //--------------------------------------------------------------------------------------------------
 JPC_API JPC_DecoratedShapeSettings *
 JPC_OffsetCenterOfMassShapeSettings_Create(const JPC_ShapeSettings *in_inner_shape_settings,
-                                           const JPC_Real in_center_of_mass[3])
+                                           const float in_center_of_mass[3])
 {
-    auto settings = new JPH::OffsetCenterOfMassShapeSettings(loadRVec3(in_center_of_mass),
+    auto settings = new JPH::OffsetCenterOfMassShapeSettings(loadVec3(in_center_of_mass),
                                                              toJph(in_inner_shape_settings));
     settings->AddRef();
     return toJpc(settings);
 }
 //--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_OffsetCenterOfMassShapeSettings_GetOffset(const JPC_DecoratedShapeSettings *in_settings,
+                                              float out_offset[3])
+{
+    ENSURE_TYPE(in_settings, JPH::OffsetCenterOfMassShapeSettings);
+    auto settings = reinterpret_cast<const JPH::OffsetCenterOfMassShapeSettings *>(in_settings);
+    storeVec3(out_offset, settings->mOffset);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_OffsetCenterOfMassShapeSettings_SetOffset(JPC_DecoratedShapeSettings *in_settings,
+                                              const float in_offset[3])
+{
+    ENSURE_TYPE(in_settings, JPH::OffsetCenterOfMassShapeSettings);
+    auto settings = reinterpret_cast<JPH::OffsetCenterOfMassShapeSettings *>(in_settings);
+    settings->mOffset = loadVec3(in_offset);
+}
+//--------------------------------------------------------------------------------------------------
 //
 // JPC_CompoundShapeSettings (-> JPC_ShapeSettings)
 //
@@ -1753,12 +2143,12 @@ JPC_MutableCompoundShapeSettings_Create()
 //--------------------------------------------------------------------------------------------------
 JPC_API void
 JPC_CompoundShapeSettings_AddShape(JPC_CompoundShapeSettings *in_settings,
-                                   const JPC_Real in_position[3],
-                                   const JPC_Real in_rotation[4],
+                                   const float in_position[3],
+                                   const float in_rotation[4],
                                    const JPC_ShapeSettings *in_shape,
                                    const uint32_t in_user_data)
 {
-    toJph(in_settings)->AddShape(loadRVec3(in_position),
+    toJph(in_settings)->AddShape(loadVec3(in_position),
                                  JPH::Quat(loadVec4(in_rotation)),
                                  toJph(in_shape),
                                  in_user_data);
@@ -1851,9 +2241,9 @@ JPC_Shape_SetUserData(JPC_Shape *in_shape, uint64_t in_user_data)
 }
 //--------------------------------------------------------------------------------------------------
 JPC_API void
-JPC_Shape_GetCenterOfMass(const JPC_Shape *in_shape, JPC_Real out_position[3])
+JPC_Shape_GetCenterOfMass(const JPC_Shape *in_shape, float out_position[3])
 {
-    storeRVec3(out_position, toJph(in_shape)->GetCenterOfMass());
+    storeVec3(out_position, toJph(in_shape)->GetCenterOfMass());
 }
 //--------------------------------------------------------------------------------------------------
 JPC_API void
@@ -1863,6 +2253,191 @@ JPC_Shape_GetMassProperties(const JPC_Shape *in_shape, JPC_MassProperties *out_m
     memcpy(out_mass_properties, &massProperties, sizeof(JPC_MassProperties));
 }
 //--------------------------------------------------------------------------------------------------
+JPC_API const JPC_PhysicsMaterial *
+JPC_Shape_GetMaterial(const JPC_Shape *in_shape, JPC_SubShapeID in_sub_shape_id)
+{
+    return toJpc(toJph(in_shape)->GetMaterial(*toJph(&in_sub_shape_id)));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_Shape_GetLocalBounds(const JPC_Shape *in_shape, float out_min[3], float out_max[3])
+{
+    const JPH::AABox bounds = toJph(in_shape)->GetLocalBounds();
+    storeVec3(out_min, bounds.mMin);
+    storeVec3(out_max, bounds.mMax);
+}
+//--------------------------------------------------------------------------------------------------
+//
+// JPC_HeightFieldShape (-> JPC_Shape)
+//
+//--------------------------------------------------------------------------------------------------
+static inline const JPH::HeightFieldShape *
+toHeightField(const JPC_Shape *in_shape)
+{
+    assert(toJph(in_shape)->GetSubType() == JPH::EShapeSubType::HeightField);
+    return static_cast<const JPH::HeightFieldShape *>(toJph(in_shape));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API uint32_t
+JPC_HeightFieldShape_GetSampleCount(const JPC_Shape *in_shape)
+{
+    return toHeightField(in_shape)->GetSampleCount();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API uint32_t
+JPC_HeightFieldShape_GetBlockSize(const JPC_Shape *in_shape)
+{
+    return toHeightField(in_shape)->GetBlockSize();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API float
+JPC_HeightFieldShape_GetMinHeightValue(const JPC_Shape *in_shape)
+{
+    return toHeightField(in_shape)->GetMinHeightValue();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API float
+JPC_HeightFieldShape_GetMaxHeightValue(const JPC_Shape *in_shape)
+{
+    return toHeightField(in_shape)->GetMaxHeightValue();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_HeightFieldShape_GetPosition(const JPC_Shape *in_shape, uint32_t in_x, uint32_t in_y, float out_position[3])
+{
+    storeVec3(out_position, toHeightField(in_shape)->GetPosition(in_x, in_y));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_HeightFieldShape_GetHeights(const JPC_Shape *in_shape,
+                                uint32_t in_x,
+                                uint32_t in_y,
+                                uint32_t in_size_x,
+                                uint32_t in_size_y,
+                                float *out_heights,
+                                intptr_t in_heights_stride)
+{
+    toHeightField(in_shape)->GetHeights(in_x, in_y, in_size_x, in_size_y, out_heights, in_heights_stride);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_HeightFieldShape_SetHeights(JPC_Shape *in_shape,
+                                uint32_t in_x,
+                                uint32_t in_y,
+                                uint32_t in_size_x,
+                                uint32_t in_size_y,
+                                const float *in_heights,
+                                intptr_t in_heights_stride,
+                                JPC_TempAllocator *in_temp_allocator)
+{
+    assert(in_temp_allocator != nullptr);
+    const_cast<JPH::HeightFieldShape *>(toHeightField(in_shape))->SetHeights(
+        in_x,
+        in_y,
+        in_size_x,
+        in_size_y,
+        in_heights,
+        in_heights_stride,
+        *reinterpret_cast<JPH::TempAllocator *>(in_temp_allocator));
+}
+//--------------------------------------------------------------------------------------------------
+//
+// JPC_GroupFilter
+//
+//--------------------------------------------------------------------------------------------------
+class GroupFilterImpl final : public JPH::GroupFilter
+{
+public:
+    JPH_OVERRIDE_NEW_DELETE
+
+    struct CFilter
+    {
+        const JPC_GroupFilterVTable *vtbl;
+    };
+
+    explicit GroupFilterImpl(CFilter *in_filter) : c_filter(in_filter) { }
+
+    ~GroupFilterImpl() override
+    {
+        if (c_filter->vtbl->Destroy)
+            c_filter->vtbl->Destroy(c_filter);
+    }
+
+    bool CanCollide(const JPH::CollisionGroup &inGroup1, const JPH::CollisionGroup &inGroup2) const override
+    {
+        return c_filter->vtbl->CanCollide(c_filter, toJpc(&inGroup1), toJpc(&inGroup2));
+    }
+
+    CFilter *c_filter;
+};
+//--------------------------------------------------------------------------------------------------
+JPC_API JPC_GroupFilter *
+JPC_GroupFilter_Create(void *in_filter)
+{
+    assert(in_filter != nullptr);
+    auto filter = new GroupFilterImpl(static_cast<GroupFilterImpl::CFilter *>(in_filter));
+    assert(filter->c_filter->vtbl->CanCollide != nullptr);
+    filter->AddRef();
+    return toJpc(filter);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_GroupFilter_AddRef(JPC_GroupFilter *in_filter)
+{
+    toJph(in_filter)->AddRef();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_GroupFilter_Release(JPC_GroupFilter *in_filter)
+{
+    toJph(in_filter)->Release();
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API bool
+JPC_GroupFilter_CanCollide(const JPC_GroupFilter *in_filter,
+                           const JPC_CollisionGroup *in_group1,
+                           const JPC_CollisionGroup *in_group2)
+{
+    return toJph(in_filter)->CanCollide(*toJph(in_group1), *toJph(in_group2));
+}
+//--------------------------------------------------------------------------------------------------
+//
+// JPC_GroupFilterTable (-> JPC_GroupFilter)
+//
+//--------------------------------------------------------------------------------------------------
+JPC_API JPC_GroupFilter *
+JPC_GroupFilterTable_Create(uint32_t in_num_sub_groups)
+{
+    auto table = new JPH::GroupFilterTable(in_num_sub_groups);
+    table->AddRef();
+    return toJpc(table);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_GroupFilterTable_DisableCollision(JPC_GroupFilter *in_table,
+                                      JPC_CollisionSubGroupID in_sub_group1,
+                                      JPC_CollisionSubGroupID in_sub_group2)
+{
+    static_cast<JPH::GroupFilterTable *>(toJph(in_table))->DisableCollision(in_sub_group1, in_sub_group2);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_GroupFilterTable_EnableCollision(JPC_GroupFilter *in_table,
+                                     JPC_CollisionSubGroupID in_sub_group1,
+                                     JPC_CollisionSubGroupID in_sub_group2)
+{
+    static_cast<JPH::GroupFilterTable *>(toJph(in_table))->EnableCollision(in_sub_group1, in_sub_group2);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API bool
+JPC_GroupFilterTable_IsCollisionEnabled(const JPC_GroupFilter *in_table,
+                                        JPC_CollisionSubGroupID in_sub_group1,
+                                        JPC_CollisionSubGroupID in_sub_group2)
+{
+    return static_cast<const JPH::GroupFilterTable *>(toJph(in_table))->IsCollisionEnabled(
+        in_sub_group1, in_sub_group2);
+}
+//--------------------------------------------------------------------------------------------------
 //
 // JPC_ConstraintSettings
 //
@@ -2017,6 +2592,57 @@ JPC_BodyInterface_RemoveBody(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id)
     toJph(in_iface)->RemoveBody(toJph(in_body_id));
 }
 //--------------------------------------------------------------------------------------------------
+JPC_API JPC_AddBodiesState *
+JPC_BodyInterface_AddBodiesPrepare(JPC_BodyInterface *in_iface, JPC_BodyID *io_bodies, int in_number)
+{
+    return static_cast<JPC_AddBodiesState *>(
+        toJph(in_iface)->AddBodiesPrepare(toJphBodyIDs(io_bodies), in_number));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_AddBodiesFinalize(JPC_BodyInterface *in_iface,
+                                    JPC_BodyID *io_bodies,
+                                    int in_number,
+                                    JPC_AddBodiesState *in_add_state,
+                                    JPC_Activation in_mode)
+{
+    toJph(in_iface)->AddBodiesFinalize(
+        toJphBodyIDs(io_bodies), in_number, in_add_state, static_cast<JPH::EActivation>(in_mode));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_AddBodiesAbort(JPC_BodyInterface *in_iface,
+                                 JPC_BodyID *io_bodies,
+                                 int in_number,
+                                 JPC_AddBodiesState *in_add_state)
+{
+    toJph(in_iface)->AddBodiesAbort(toJphBodyIDs(io_bodies), in_number, in_add_state);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_RemoveBodies(JPC_BodyInterface *in_iface, JPC_BodyID *io_bodies, int in_number)
+{
+    toJph(in_iface)->RemoveBodies(toJphBodyIDs(io_bodies), in_number);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_DestroyBodies(JPC_BodyInterface *in_iface, const JPC_BodyID *in_bodies, int in_number)
+{
+    toJph(in_iface)->DestroyBodies(toJphBodyIDs(in_bodies), in_number);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_ActivateBodies(JPC_BodyInterface *in_iface, const JPC_BodyID *in_bodies, int in_number)
+{
+    toJph(in_iface)->ActivateBodies(toJphBodyIDs(in_bodies), in_number);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_DeactivateBodies(JPC_BodyInterface *in_iface, const JPC_BodyID *in_bodies, int in_number)
+{
+    toJph(in_iface)->DeactivateBodies(toJphBodyIDs(in_bodies), in_number);
+}
+//--------------------------------------------------------------------------------------------------
 JPC_API JPC_BodyID
 JPC_BodyInterface_CreateAndAddBody(JPC_BodyInterface *in_iface,
                                    const JPC_BodyCreationSettings *in_settings,
@@ -2141,7 +2767,7 @@ JPC_BodyInterface_GetCenterOfMassPosition(const JPC_BodyInterface *in_iface,
 JPC_API void
 JPC_BodyInterface_SetRotation(JPC_BodyInterface *in_iface,
                               JPC_BodyID in_body_id,
-                              const JPC_Real in_rotation[4],
+                              const float in_rotation[4],
                               JPC_Activation in_activation)
 {
     toJph(in_iface)->SetRotation(toJph(in_body_id), JPH::Quat(loadVec4(in_rotation)), static_cast<JPH::EActivation>(in_activation));
@@ -2264,6 +2890,195 @@ JPC_BodyInterface_SetObjectLayer(JPC_BodyInterface *in_iface, JPC_BodyID in_body
     toJph(in_iface)->SetObjectLayer(toJph(in_body_id), static_cast<JPH::ObjectLayer>(in_layer));
 }
 //--------------------------------------------------------------------------------------------------
+JPC_API const JPC_Shape *
+JPC_BodyInterface_GetShape(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id)
+{
+    JPH::RefConst<JPH::Shape> shape = toJph(in_iface)->GetShape(toJph(in_body_id));
+    if (shape == nullptr) return nullptr;
+    shape->AddRef();
+    return toJpc(shape.GetPtr());
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_SetShape(JPC_BodyInterface *in_iface,
+                           JPC_BodyID in_body_id,
+                           const JPC_Shape *in_shape,
+                           bool in_update_mass_properties,
+                           JPC_Activation in_activation)
+{
+    toJph(in_iface)->SetShape(
+        toJph(in_body_id),
+        toJph(in_shape),
+        in_update_mass_properties,
+        static_cast<JPH::EActivation>(in_activation));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_NotifyShapeChanged(JPC_BodyInterface *in_iface,
+                                     JPC_BodyID in_body_id,
+                                     const float in_previous_center_of_mass[3],
+                                     bool in_update_mass_properties,
+                                     JPC_Activation in_activation)
+{
+    toJph(in_iface)->NotifyShapeChanged(
+        toJph(in_body_id),
+        loadVec3(in_previous_center_of_mass),
+        in_update_mass_properties,
+        static_cast<JPH::EActivation>(in_activation));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_SetPositionAndRotation(JPC_BodyInterface *in_iface,
+                                         JPC_BodyID in_body_id,
+                                         const JPC_Real in_position[3],
+                                         const float in_rotation[4],
+                                         JPC_Activation in_activation)
+{
+    toJph(in_iface)->SetPositionAndRotation(
+        toJph(in_body_id),
+        loadRVec3(in_position),
+        JPH::Quat(loadVec4(in_rotation)),
+        static_cast<JPH::EActivation>(in_activation));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_SetPositionAndRotationWhenChanged(JPC_BodyInterface *in_iface,
+                                                    JPC_BodyID in_body_id,
+                                                    const JPC_Real in_position[3],
+                                                    const float in_rotation[4],
+                                                    JPC_Activation in_activation)
+{
+    toJph(in_iface)->SetPositionAndRotationWhenChanged(
+        toJph(in_body_id),
+        loadRVec3(in_position),
+        JPH::Quat(loadVec4(in_rotation)),
+        static_cast<JPH::EActivation>(in_activation));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_GetWorldTransform(JPC_BodyInterface *in_iface,
+                                    JPC_BodyID in_body_id,
+                                    float out_rotation[9],
+                                    JPC_Real out_translation[3])
+{
+    const JPH::RMat44 m = toJph(in_iface)->GetWorldTransform(toJph(in_body_id));
+    storeVec3(&out_rotation[0], m.GetColumn3(0));
+    storeVec3(&out_rotation[3], m.GetColumn3(1));
+    storeVec3(&out_rotation[6], m.GetColumn3(2));
+    storeRVec3(&out_translation[0], m.GetTranslation());
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_GetCenterOfMassTransform(JPC_BodyInterface *in_iface,
+                                           JPC_BodyID in_body_id,
+                                           float out_rotation[9],
+                                           JPC_Real out_translation[3])
+{
+    const JPH::RMat44 m = toJph(in_iface)->GetCenterOfMassTransform(toJph(in_body_id));
+    storeVec3(&out_rotation[0], m.GetColumn3(0));
+    storeVec3(&out_rotation[3], m.GetColumn3(1));
+    storeVec3(&out_rotation[6], m.GetColumn3(2));
+    storeRVec3(&out_translation[0], m.GetTranslation());
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_MoveKinematic(JPC_BodyInterface *in_iface,
+                                JPC_BodyID in_body_id,
+                                const JPC_Real in_target_position[3],
+                                const float in_target_rotation[4],
+                                float in_delta_time)
+{
+    toJph(in_iface)->MoveKinematic(
+        toJph(in_body_id),
+        loadRVec3(in_target_position),
+        JPH::Quat(loadVec4(in_target_rotation)),
+        in_delta_time);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API float
+JPC_BodyInterface_GetFriction(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id)
+{
+    return toJph(in_iface)->GetFriction(toJph(in_body_id));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_SetFriction(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, float in_friction)
+{
+    toJph(in_iface)->SetFriction(toJph(in_body_id), in_friction);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API float
+JPC_BodyInterface_GetRestitution(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id)
+{
+    return toJph(in_iface)->GetRestitution(toJph(in_body_id));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_SetRestitution(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, float in_restitution)
+{
+    toJph(in_iface)->SetRestitution(toJph(in_body_id), in_restitution);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API float
+JPC_BodyInterface_GetGravityFactor(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id)
+{
+    return toJph(in_iface)->GetGravityFactor(toJph(in_body_id));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_SetGravityFactor(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, float in_gravity_factor)
+{
+    toJph(in_iface)->SetGravityFactor(toJph(in_body_id), in_gravity_factor);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API uint64_t
+JPC_BodyInterface_GetUserData(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id)
+{
+    return toJph(in_iface)->GetUserData(toJph(in_body_id));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_SetUserData(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, uint64_t in_user_data)
+{
+    toJph(in_iface)->SetUserData(toJph(in_body_id), in_user_data);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_GetInverseInertia(JPC_BodyInterface *in_iface,
+                                    JPC_BodyID in_body_id,
+                                    float out_inverse_inertia[16])
+{
+    storeMat44(out_inverse_inertia, toJph(in_iface)->GetInverseInertia(toJph(in_body_id)));
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_ActivateBodiesInAABox(JPC_BodyInterface *in_iface,
+                                        const float in_min[3],
+                                        const float in_max[3],
+                                        const void *in_broad_phase_layer_filter,
+                                        const void *in_object_layer_filter)
+{
+    const JPH::BroadPhaseLayerFilter broad_phase_layer_filter{};
+    const JPH::ObjectLayerFilter object_layer_filter{};
+
+    toJph(in_iface)->ActivateBodiesInAABox(
+        JPH::AABox(loadVec3(in_min), loadVec3(in_max)),
+        in_broad_phase_layer_filter ?
+            *static_cast<const JPH::BroadPhaseLayerFilter *>(in_broad_phase_layer_filter) :
+            broad_phase_layer_filter,
+        in_object_layer_filter ?
+            *static_cast<const JPH::ObjectLayerFilter *>(in_object_layer_filter) : object_layer_filter);
+}
+//--------------------------------------------------------------------------------------------------
+JPC_API void
+JPC_BodyInterface_GetTransformedShape(JPC_BodyInterface *in_iface,
+                                      JPC_BodyID in_body_id,
+                                      JPC_TransformedShape *out_shape)
+{
+    assert(out_shape != nullptr);
+    ::new (out_shape) JPH::TransformedShape(toJph(in_iface)->GetTransformedShape(toJph(in_body_id)));
+}
+//--------------------------------------------------------------------------------------------------
 //
 // JPC_Body
 //
@@ -3121,6 +3936,15 @@ JPC_API JPC_Features JPC_GetFeatures()
 #ifdef _DEBUG
 		features |= 1 << 14;
 #endif
+#ifdef JPH_DEBUG_RENDERER
+		features |= 1 << 15;
+#endif
+#ifdef JPH_PROFILE_ENABLED
+		features |= 1 << 16;
+#endif
+#ifdef JPH_ENABLE_ASSERTS
+		features |= 1 << 17;
+#endif
 
     return features;
 }
@@ -11,10 +11,15 @@
 // Const
 //
 //--------------------------------------------------------------------------------------------------
-#define JPC_API extern __declspec(dllexport) // TODO: Define this properly
+#if defined(_WIN32)
+    #define JPC_API extern __declspec(dllexport)
+#else
+    #define JPC_API extern __attribute__((visibility("default")))
+#endif
 
-// Always turn on asserts in Debug mode
-#if defined(_DEBUG) || defined(JPH_ENABLE_ASSERTS)
+// Only follows JPH_ENABLE_ASSERTS, _DEBUG also turns on asserts in Jolt but the bindings can't see it. Building
+// Jolt with _DEBUG and without JPH_ENABLE_ASSERTS fails the layout checks in JoltPhysicsC_Extensions.cpp.
+#if defined(JPH_ENABLE_ASSERTS)
     #define JPC_ENABLE_ASSERTS 1
 #else
     #define JPC_ENABLE_ASSERTS 0
@@ -74,6 +79,14 @@ typedef enum JPC_EPhysicsUpdateError
     JPC_PHYSICS_UPDATE_CONTACT_CONSTRAINTS_FULL = 1 << 2,
 } JPC_EPhysicsUpdateError;
 
+typedef uint8_t JPC_TempAllocatorStrategy;
+typedef enum JPC_ETempAllocatorStrategy
+{
+    JPC_TEMP_ALLOCATOR_FIXED                 = 0,
+    JPC_TEMP_ALLOCATOR_FIXED_MALLOC_FALLBACK = 1,
+    JPC_TEMP_ALLOCATOR_GROWABLE              = 2,
+} JPC_ETempAllocatorStrategy;
+
 typedef uint8_t JPC_ShapeType;
 typedef enum JPC_EShapeType
 {
@@ -235,6 +248,9 @@ typedef enum JPC_EFeatures {
     JPC_FEATURE_PLATFORM_DETERMINISTIC = (1 << 12),
     JPC_FEATURE_FLOATING_POINT_EXCEPTIONS = (1 << 13),
     JPC_FEATURE_DEBUG = (1 << 14),
+    JPC_FEATURE_DEBUG_RENDERER = (1 << 15),
+    JPC_FEATURE_PROFILER = (1 << 16),
+    JPC_FEATURE_ASSERTS = (1 << 17),
 } JPC_EFeatures;
 
 typedef uint8_t JPC_AllowedDOFs;
@@ -313,6 +329,7 @@ typedef void *(*JPC_AlignedAllocateFunction)(size_t in_size, size_t in_alignment
 typedef void (*JPC_AlignedFreeFunction)(void *in_block);
 
 typedef bool (*JPC_AssertFailedFunction)(const char *inExpression, const char *inMessage, const char *inFile, unsigned int inLine);
+typedef void (*JPC_TraceFunction)(const char *in_message);
 //--------------------------------------------------------------------------------------------------
 //
 // Opaque Types
@@ -320,7 +337,9 @@ typedef bool (*JPC_AssertFailedFunction)(const char *inExpression, const char *i
 //--------------------------------------------------------------------------------------------------
 typedef struct JPC_TempAllocator     JPC_TempAllocator;
 typedef struct JPC_JobSystem         JPC_JobSystem;
+typedef struct JPC_Job               JPC_Job;
 typedef struct JPC_BodyInterface     JPC_BodyInterface;
+typedef struct JPC_AddBodiesState    JPC_AddBodiesState;
 typedef struct JPC_BodyLockInterface JPC_BodyLockInterface;
 typedef struct JPC_NarrowPhaseQuery  JPC_NarrowPhaseQuery;
 
@@ -358,11 +377,23 @@ typedef struct JPC_BodyDrawFilter              JPC_BodyDrawFilter;
 typedef struct JPC_DebugRenderer_TriangleBatch JPC_DebugRenderer_TriangleBatch;
 typedef struct JPC_DebugRenderer_Primitive     JPC_DebugRenderer_Primitive;
 #endif //JPC_DEBUG_RENDERER
+
+// Called for every job that is ready to run. The callee must eventually call JPC_Job_Execute() followed by
+// JPC_Job_Release() on the job, from any thread.
+typedef void (*JPC_QueueJobFunction)(void *in_user_data, JPC_Job *in_job);
 //--------------------------------------------------------------------------------------------------
 //
 // Structures
 //
 //--------------------------------------------------------------------------------------------------
+typedef struct JPC_TempAllocatorStats
+{
+    uint32_t capacity;
+    uint32_t usage;
+    uint32_t high_water_mark;
+    uint32_t fallback_allocations;
+} JPC_TempAllocatorStats;
+
 // NOTE: Needs to be kept in sync with JPH::MassProperties
 typedef struct JPC_MassProperties
 {
@@ -908,6 +939,19 @@ typedef struct JPC_ContactListenerVTable
     (*OnContactRemoved)(void *in_self, const JPC_SubShapeIDPair *in_sub_shape_pair);
 } JPC_ContactListenerVTable;
 
+/// Not pointer-compatible with JPH::GroupFilter (which is reference counted), JPC_GroupFilter_Create() wraps
+/// it in a JPH::GroupFilter implementation instead.
+typedef struct JPC_GroupFilterVTable
+{
+    // Required, *cannot* be NULL.
+    bool
+    (*CanCollide)(const void *in_self, const JPC_CollisionGroup *in_group1, const JPC_CollisionGroup *in_group2);
+
+    // Optional, can be NULL. Called when the last reference to the filter is released.
+    void
+    (*Destroy)(void *in_self);
+} JPC_GroupFilterVTable;
+
 #if JPC_DEBUG_RENDERER == 1
 /// Although used similarly to the VTables above, this struct is not pointer-compatible with JPH::DebugRenderer
 /// Instead, it's wrapped by the DebugRendererImpl inheritor class (as seen in JoltPhysicsC.cpp), because
@@ -988,6 +1032,9 @@ JPC_BodyCreationSettings_Set(JPC_BodyCreationSettings *out_settings,
 JPC_API void
 JPC_SetAssertFailedHandler(JPC_AssertFailedFunction in_handler);
 
+JPC_API void
+JPC_SetTraceHandler(JPC_TraceFunction in_handler);
+
 #if JPC_DEBUG_RENDERER == 1
 /// Provide an instantiated VTable to get wrapped by the singleton implementation of JPH::DebugRenderer. This should be
 /// called only once, at program initialization, as when instantiating a DebugRenderer implementation in Jolt proper.
@@ -1141,8 +1188,19 @@ JPC_MotionProperties_SetMaxAngularVelocity(JPC_MotionProperties *in_properties,
 JPC_API JPC_TempAllocator *
 JPC_TempAllocator_Create(uint32_t in_size);
 
+JPC_API JPC_TempAllocator *
+JPC_TempAllocator_CreateWithStrategy(JPC_TempAllocatorStrategy in_strategy, uint32_t in_size);
+
 JPC_API void
 JPC_TempAllocator_Destroy(JPC_TempAllocator *in_allocator);
+
+JPC_API void
+JPC_TempAllocator_GetStats(const JPC_TempAllocator *in_allocator, JPC_TempAllocatorStats *out_stats);
+
+/// Resets the high water mark and fallback allocation count. A JPC_TEMP_ALLOCATOR_GROWABLE allocator first grows
+/// its arena to the high water mark, if nothing is allocated.
+JPC_API void
+JPC_TempAllocator_ResetStats(JPC_TempAllocator *in_allocator);
 //--------------------------------------------------------------------------------------------------
 //
 // JPC_JobSystem
@@ -1151,8 +1209,31 @@ JPC_TempAllocator_Destroy(JPC_TempAllocator *in_allocator);
 JPC_API JPC_JobSystem *
 JPC_JobSystem_Create(uint32_t in_max_jobs, uint32_t in_max_barriers, int in_num_threads);
 
+/// Creates a job system that runs every job inline on the thread calling JPC_PhysicsSystem_Update().
+JPC_API JPC_JobSystem *
+JPC_JobSystem_CreateSingleThreaded(uint32_t in_max_jobs);
+
+/// Creates a job system that hands every job to `in_queue_job` instead of running its own threads.
+/// Must be destroyed with JPC_JobSystem_DestroyCustom().
+JPC_API JPC_JobSystem *
+JPC_JobSystem_CreateCustom(uint32_t in_max_barriers,
+                           uint32_t in_max_concurrency,
+                           JPC_QueueJobFunction in_queue_job,
+                           void *in_user_data);
+
 JPC_API void
 JPC_JobSystem_Destroy(JPC_JobSystem *in_job_system);
+
+/// Stops `in_queue_job` from being called. Queued jobs keep the job system alive, it is freed once
+/// the last of them has been passed to JPC_Job_Release().
+JPC_API void
+JPC_JobSystem_DestroyCustom(JPC_JobSystem *in_job_system);
+
+JPC_API void
+JPC_Job_Execute(JPC_Job *in_job);
+
+JPC_API void
+JPC_Job_Release(JPC_Job *in_job);
 //--------------------------------------------------------------------------------------------------
 //
 // JPC_PhysicsSystem
@@ -1317,6 +1398,27 @@ JPC_NarrowPhaseQuery_CastRay(const JPC_NarrowPhaseQuery *in_query,
                              const void *in_body_filter); // Can be NULL (no filter)
 //--------------------------------------------------------------------------------------------------
 //
+// JPC_PhysicsMaterial
+//
+//--------------------------------------------------------------------------------------------------
+/// Creates a JPH::PhysicsMaterialSimple, the returned material starts with a reference count of 1.
+/// Colors are packed like JPH::Color (r in the lowest byte, a in the highest).
+JPC_API JPC_PhysicsMaterial *
+JPC_PhysicsMaterial_Create(const char *in_debug_name, uint32_t in_debug_color);
+
+JPC_API void
+JPC_PhysicsMaterial_AddRef(const JPC_PhysicsMaterial *in_material);
+
+JPC_API void
+JPC_PhysicsMaterial_Release(const JPC_PhysicsMaterial *in_material);
+
+JPC_API const char *
+JPC_PhysicsMaterial_GetDebugName(const JPC_PhysicsMaterial *in_material);
+
+JPC_API uint32_t
+JPC_PhysicsMaterial_GetDebugColor(const JPC_PhysicsMaterial *in_material);
+//--------------------------------------------------------------------------------------------------
+//
 // JPC_ShapeSettings
 //
 //--------------------------------------------------------------------------------------------------
@@ -1543,6 +1645,32 @@ JPC_HeightFieldShapeSettings_GetBitsPerSample(const JPC_HeightFieldShapeSettings
 
 JPC_API void
 JPC_HeightFieldShapeSettings_SetBitsPerSample(JPC_HeightFieldShapeSettings *in_settings, uint32_t in_num_bits);
+
+JPC_API float
+JPC_HeightFieldShapeSettings_GetMinHeightValue(const JPC_HeightFieldShapeSettings *in_settings);
+
+/// Lowest height the shape can represent, samples below it extend the range instead.
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMinHeightValue(JPC_HeightFieldShapeSettings *in_settings, float in_min_height_value);
+
+JPC_API float
+JPC_HeightFieldShapeSettings_GetMaxHeightValue(const JPC_HeightFieldShapeSettings *in_settings);
+
+/// Highest height the shape can represent, samples above it extend the range instead.
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMaxHeightValue(JPC_HeightFieldShapeSettings *in_settings, float in_max_height_value);
+
+/// Replaces the material list, the settings take a reference to each material.
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMaterials(JPC_HeightFieldShapeSettings *in_settings,
+                                          const JPC_PhysicsMaterial *const *in_materials,
+                                          uint32_t in_num_materials);
+
+/// One index into the material list per cell, (height_field_size - 1)^2 in total.
+JPC_API void
+JPC_HeightFieldShapeSettings_SetMaterialIndices(JPC_HeightFieldShapeSettings *in_settings,
+                                                const uint8_t *in_material_indices,
+                                                uint32_t in_num_material_indices);
 //--------------------------------------------------------------------------------------------------
 //
 // JPC_MeshShapeSettings (-> JPC_ShapeSettings)
@@ -1569,16 +1697,24 @@ JPC_MeshShapeSettings_Sanitize(JPC_MeshShapeSettings *in_settings);
 //--------------------------------------------------------------------------------------------------
 JPC_API JPC_DecoratedShapeSettings *
 JPC_RotatedTranslatedShapeSettings_Create(const JPC_ShapeSettings *in_inner_shape_settings,
-                                          const JPC_Real in_rotated[4],
-                                          const JPC_Real in_translated[3]);
+                                          const float in_rotated[4],
+                                          const float in_translated[3]);
 
 JPC_API JPC_DecoratedShapeSettings *
 JPC_ScaledShapeSettings_Create(const JPC_ShapeSettings *in_inner_shape_settings,
-                               const JPC_Real in_scale[3]);
+                               const float in_scale[3]);
 
 JPC_API JPC_DecoratedShapeSettings *
 JPC_OffsetCenterOfMassShapeSettings_Create(const JPC_ShapeSettings *in_inner_shape_settings,
-                                           const JPC_Real in_center_of_mass[3]);
+                                           const float in_center_of_mass[3]);
+
+JPC_API void
+JPC_OffsetCenterOfMassShapeSettings_GetOffset(const JPC_DecoratedShapeSettings *in_settings,
+                                              float out_offset[3]);
+
+JPC_API void
+JPC_OffsetCenterOfMassShapeSettings_SetOffset(JPC_DecoratedShapeSettings *in_settings,
+                                              const float in_offset[3]);
 //--------------------------------------------------------------------------------------------------
 //
 // JPC_CompoundShapeSettings (-> JPC_ShapeSettings)
@@ -1592,8 +1728,8 @@ JPC_MutableCompoundShapeSettings_Create();
 
 JPC_API void
 JPC_CompoundShapeSettings_AddShape(JPC_CompoundShapeSettings *in_settings,
-                                   const JPC_Real in_position[3],
-                                   const JPC_Real in_rotation[4],
+                                   const float in_position[3],
+                                   const float in_rotation[4],
                                    const JPC_ShapeSettings *in_shape,
                                    const uint32_t in_user_data);
 //--------------------------------------------------------------------------------------------------
@@ -1647,10 +1783,103 @@ JPC_API void
 JPC_Shape_SetUserData(JPC_Shape *in_shape, uint64_t in_user_data);
 
 JPC_API void
-JPC_Shape_GetCenterOfMass(const JPC_Shape *in_shape, JPC_Real out_position[3]);
+JPC_Shape_GetCenterOfMass(const JPC_Shape *in_shape, float out_position[3]);
 
 JPC_API void
 JPC_Shape_GetMassProperties(const JPC_Shape *in_shape, JPC_MassProperties *out_mass_properties);
+
+/// Returned material is not reference counted, it lives as long as the shape does.
+JPC_API const JPC_PhysicsMaterial *
+JPC_Shape_GetMaterial(const JPC_Shape *in_shape, JPC_SubShapeID in_sub_shape_id);
+
+JPC_API void
+JPC_Shape_GetLocalBounds(const JPC_Shape *in_shape, float out_min[3], float out_max[3]);
+//--------------------------------------------------------------------------------------------------
+//
+// JPC_HeightFieldShape (-> JPC_Shape)
+//
+//--------------------------------------------------------------------------------------------------
+JPC_API uint32_t
+JPC_HeightFieldShape_GetSampleCount(const JPC_Shape *in_shape);
+
+JPC_API uint32_t
+JPC_HeightFieldShape_GetBlockSize(const JPC_Shape *in_shape);
+
+JPC_API float
+JPC_HeightFieldShape_GetMinHeightValue(const JPC_Shape *in_shape);
+
+JPC_API float
+JPC_HeightFieldShape_GetMaxHeightValue(const JPC_Shape *in_shape);
+
+/// Local space position of sample (in_x, in_y).
+JPC_API void
+JPC_HeightFieldShape_GetPosition(const JPC_Shape *in_shape, uint32_t in_x, uint32_t in_y, float out_position[3]);
+
+/// Reads in_size_x * in_size_y samples starting at (in_x, in_y), rows are in_heights_stride floats apart.
+JPC_API void
+JPC_HeightFieldShape_GetHeights(const JPC_Shape *in_shape,
+                                uint32_t in_x,
+                                uint32_t in_y,
+                                uint32_t in_size_x,
+                                uint32_t in_size_y,
+                                float *out_heights,
+                                intptr_t in_heights_stride);
+
+/// in_x, in_y, in_size_x and in_size_y must be multiples of the block size, sizes may also reach the
+/// edge of the grid. Heights are clamped to the min/max height value of the shape.
+/// Must not be called while the shape is used by a physics update or query.
+JPC_API void
+JPC_HeightFieldShape_SetHeights(JPC_Shape *in_shape,
+                                uint32_t in_x,
+                                uint32_t in_y,
+                                uint32_t in_size_x,
+                                uint32_t in_size_y,
+                                const float *in_heights,
+                                intptr_t in_heights_stride,
+                                JPC_TempAllocator *in_temp_allocator);
+//--------------------------------------------------------------------------------------------------
+//
+// JPC_GroupFilter
+//
+//--------------------------------------------------------------------------------------------------
+/// `in_filter` must point to a struct whose first member is a pointer to a JPC_GroupFilterVTable.
+/// The returned filter starts with a reference count of 1.
+JPC_API JPC_GroupFilter *
+JPC_GroupFilter_Create(void *in_filter);
+
+JPC_API void
+JPC_GroupFilter_AddRef(JPC_GroupFilter *in_filter);
+
+JPC_API void
+JPC_GroupFilter_Release(JPC_GroupFilter *in_filter);
+
+JPC_API bool
+JPC_GroupFilter_CanCollide(const JPC_GroupFilter *in_filter,
+                           const JPC_CollisionGroup *in_group1,
+                           const JPC_CollisionGroup *in_group2);
+//--------------------------------------------------------------------------------------------------
+//
+// JPC_GroupFilterTable (-> JPC_GroupFilter)
+//
+//--------------------------------------------------------------------------------------------------
+/// The returned filter starts with a reference count of 1.
+JPC_API JPC_GroupFilter *
+JPC_GroupFilterTable_Create(uint32_t in_num_sub_groups);
+
+JPC_API void
+JPC_GroupFilterTable_DisableCollision(JPC_GroupFilter *in_table,
+                                      JPC_CollisionSubGroupID in_sub_group1,
+                                      JPC_CollisionSubGroupID in_sub_group2);
+
+JPC_API void
+JPC_GroupFilterTable_EnableCollision(JPC_GroupFilter *in_table,
+                                     JPC_CollisionSubGroupID in_sub_group1,
+                                     JPC_CollisionSubGroupID in_sub_group2);
+
+JPC_API bool
+JPC_GroupFilterTable_IsCollisionEnabled(const JPC_GroupFilter *in_table,
+                                        JPC_CollisionSubGroupID in_sub_group1,
+                                        JPC_CollisionSubGroupID in_sub_group2);
 //--------------------------------------------------------------------------------------------------
 //
 // JPC_ConstraintSettings
@@ -1739,6 +1968,35 @@ JPC_BodyInterface_AddBody(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, JP
 JPC_API void
 JPC_BodyInterface_RemoveBody(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id);
 
+// Bulk insertion: `io_bodies` may be reordered, pass the same array to Finalize/Abort.
+JPC_API JPC_AddBodiesState *
+JPC_BodyInterface_AddBodiesPrepare(JPC_BodyInterface *in_iface, JPC_BodyID *io_bodies, int in_number);
+
+JPC_API void
+JPC_BodyInterface_AddBodiesFinalize(JPC_BodyInterface *in_iface,
+                                    JPC_BodyID *io_bodies,
+                                    int in_number,
+                                    JPC_AddBodiesState *in_add_state,
+                                    JPC_Activation in_mode);
+
+JPC_API void
+JPC_BodyInterface_AddBodiesAbort(JPC_BodyInterface *in_iface,
+                                 JPC_BodyID *io_bodies,
+                                 int in_number,
+                                 JPC_AddBodiesState *in_add_state);
+
+JPC_API void
+JPC_BodyInterface_RemoveBodies(JPC_BodyInterface *in_iface, JPC_BodyID *io_bodies, int in_number);
+
+JPC_API void
+JPC_BodyInterface_DestroyBodies(JPC_BodyInterface *in_iface, const JPC_BodyID *in_bodies, int in_number);
+
+JPC_API void
+JPC_BodyInterface_ActivateBodies(JPC_BodyInterface *in_iface, const JPC_BodyID *in_bodies, int in_number);
+
+JPC_API void
+JPC_BodyInterface_DeactivateBodies(JPC_BodyInterface *in_iface, const JPC_BodyID *in_bodies, int in_number);
+
 JPC_API JPC_BodyID
 JPC_BodyInterface_CreateAndAddBody(JPC_BodyInterface *in_iface,
                                    const JPC_BodyCreationSettings *in_settings,
@@ -1806,7 +2064,7 @@ JPC_BodyInterface_GetRotation(const JPC_BodyInterface *in_iface,
 JPC_API void
 JPC_BodyInterface_SetRotation(JPC_BodyInterface *in_iface,
                               JPC_BodyID in_body_id,
-                              const JPC_Real in_rotation[4],
+                              const float in_rotation[4],
                               JPC_Activation in_activation);
 JPC_API void
 JPC_BodyInterface_ActivateBody(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id);
@@ -1862,6 +2120,102 @@ JPC_BodyInterface_GetObjectLayer(JPC_BodyInterface *in_iface, JPC_BodyID in_body
 
 JPC_API void
 JPC_BodyInterface_SetObjectLayer(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, JPC_ObjectLayer in_layer);
+
+// Returned shape has its reference count incremented, release it with JPC_Shape_Release().
+// Returns NULL if the body ID is invalid.
+JPC_API const JPC_Shape *
+JPC_BodyInterface_GetShape(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id);
+
+JPC_API void
+JPC_BodyInterface_SetShape(JPC_BodyInterface *in_iface,
+                           JPC_BodyID in_body_id,
+                           const JPC_Shape *in_shape,
+                           bool in_update_mass_properties,
+                           JPC_Activation in_activation);
+
+JPC_API void
+JPC_BodyInterface_NotifyShapeChanged(JPC_BodyInterface *in_iface,
+                                     JPC_BodyID in_body_id,
+                                     const float in_previous_center_of_mass[3],
+                                     bool in_update_mass_properties,
+                                     JPC_Activation in_activation);
+
+JPC_API void
+JPC_BodyInterface_SetPositionAndRotation(JPC_BodyInterface *in_iface,
+                                         JPC_BodyID in_body_id,
+                                         const JPC_Real in_position[3],
+                                         const float in_rotation[4],
+                                         JPC_Activation in_activation);
+
+JPC_API void
+JPC_BodyInterface_SetPositionAndRotationWhenChanged(JPC_BodyInterface *in_iface,
+                                                    JPC_BodyID in_body_id,
+                                                    const JPC_Real in_position[3],
+                                                    const float in_rotation[4],
+                                                    JPC_Activation in_activation);
+
+JPC_API void
+JPC_BodyInterface_GetWorldTransform(JPC_BodyInterface *in_iface,
+                                    JPC_BodyID in_body_id,
+                                    float out_rotation[9],
+                                    JPC_Real out_translation[3]);
+
+JPC_API void
+JPC_BodyInterface_GetCenterOfMassTransform(JPC_BodyInterface *in_iface,
+                                           JPC_BodyID in_body_id,
+                                           float out_rotation[9],
+                                           JPC_Real out_translation[3]);
+
+JPC_API void
+JPC_BodyInterface_MoveKinematic(JPC_BodyInterface *in_iface,
+                                JPC_BodyID in_body_id,
+                                const JPC_Real in_target_position[3],
+                                const float in_target_rotation[4],
+                                float in_delta_time);
+
+JPC_API float
+JPC_BodyInterface_GetFriction(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id);
+
+JPC_API void
+JPC_BodyInterface_SetFriction(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, float in_friction);
+
+JPC_API float
+JPC_BodyInterface_GetRestitution(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id);
+
+JPC_API void
+JPC_BodyInterface_SetRestitution(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, float in_restitution);
+
+JPC_API float
+JPC_BodyInterface_GetGravityFactor(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id);
+
+JPC_API void
+JPC_BodyInterface_SetGravityFactor(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, float in_gravity_factor);
+
+JPC_API uint64_t
+JPC_BodyInterface_GetUserData(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id);
+
+JPC_API void
+JPC_BodyInterface_SetUserData(JPC_BodyInterface *in_iface, JPC_BodyID in_body_id, uint64_t in_user_data);
+
+JPC_API void
+JPC_BodyInterface_GetInverseInertia(JPC_BodyInterface *in_iface,
+                                    JPC_BodyID in_body_id,
+                                    float out_inverse_inertia[16]);
+
+// Filters are optional (can be NULL)
+JPC_API void
+JPC_BodyInterface_ActivateBodiesInAABox(JPC_BodyInterface *in_iface,
+                                        const float in_min[3],
+                                        const float in_max[3],
+                                        const void *in_broad_phase_layer_filter,
+                                        const void *in_object_layer_filter);
+
+// `out_shape` holds a reference to the body's shape, release it with JPC_Shape_Release().
+// The shape is NULL if the body ID is invalid.
+JPC_API void
+JPC_BodyInterface_GetTransformedShape(JPC_BodyInterface *in_iface,
+                                      JPC_BodyID in_body_id,
+                                      JPC_TransformedShape *out_shape);
 //--------------------------------------------------------------------------------------------------
 //
 // JPC_Body
@@ -5,6 +5,9 @@
 #ifdef _MSC_VER
 #define _ALLOW_KEYWORD_MACROS
 #endif
+// Standard headers break when their private members are made public (libstdc++ redeclares some of them with a
+// different access), so they have to be included first.
+#include <sstream>
 // We do this because we add some low-level functions which need access to private fields.
 // Also, we static assert offsets of some private fields (see bottom of this file).
 #define private public
@@ -27,6 +30,7 @@
 #include <Jolt/Physics/Collision/Shape/TaperedCapsuleShape.h>
 #include <Jolt/Physics/Collision/Shape/CylinderShape.h>
 #include <Jolt/Physics/Collision/Shape/ConvexHullShape.h>
+#include <Jolt/Physics/Collision/Shape/HeightFieldShape.h>
 #include <Jolt/Physics/Body/AllowedDOFs.h>
 #include <Jolt/Physics/Body/BodyCreationSettings.h>
 #include <Jolt/Physics/Body/BodyActivationListener.h>
@@ -108,6 +112,8 @@ static_assert(JPC_BODY_ID_INVALID                   == JPH::BodyID::cInvalidBody
 static_assert(JPC_BODY_ID_INDEX_BITS                == JPH::BodyID::cMaxBodyIndex);
 static_assert(_JPC_IS_FREED_BODY_BIT                == JPH::BodyManager::cIsFreedBody);
 static_assert(JPC_SUB_SHAPE_ID_EMPTY                == JPH::SubShapeID::cEmpty);
+// The Rust bindings mark height field holes with f32::MAX
+static_assert(JPH::HeightFieldShapeConstants::cNoCollisionValue == FLT_MAX);
 
 static_assert((JPC_BODY_ID_SEQUENCE_BITS >> JPC_BODY_ID_SEQUENCE_SHIFT) == JPH::BodyID::cMaxSequenceNumber);
 //--------------------------------------------------------------------------------------------------
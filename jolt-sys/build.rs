@@ -1,19 +1,107 @@
 use std::env;
 use std::path::PathBuf;
 
+// Cargo feature, CMake option and the define Jolt's headers expect for it.
+const FEATURES: &[(&str, &[&str], &str)] = &[
+    (
+        "DOUBLE_PRECISION",
+        &["DOUBLE_PRECISION"],
+        "JPH_DOUBLE_PRECISION",
+    ),
+    (
+        "CROSS_PLATFORM_DETERMINISTIC",
+        &["CROSS_PLATFORM_DETERMINISTIC"],
+        "JPH_CROSS_PLATFORM_DETERMINISTIC",
+    ),
+    (
+        "DEBUG_RENDERER",
+        &[
+            "DEBUG_RENDERER_IN_DEBUG_AND_RELEASE",
+            "DEBUG_RENDERER_IN_DISTRIBUTION",
+        ],
+        "JPH_DEBUG_RENDERER",
+    ),
+    (
+        "PROFILER",
+        &["PROFILER_IN_DEBUG_AND_RELEASE", "PROFILER_IN_DISTRIBUTION"],
+        "JPH_PROFILE_ENABLED",
+    ),
+    ("ASSERTS", &["USE_ASSERTS"], "JPH_ENABLE_ASSERTS"),
+    (
+        "FLOATING_POINT_EXCEPTIONS",
+        &["FLOATING_POINT_EXCEPTIONS_ENABLED"],
+        "JPH_FLOATING_POINT_EXCEPTIONS_ENABLED",
+    ),
+];
+
+fn feature_enabled(feature: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{feature}")).is_some()
+}
+
 fn main() {
+    println!("cargo:rerun-if-env-changed=JOLT_SYS_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=JOLT_SYS_UPDATE_BINDINGS");
+
+    if let Some(lib_dir) = env::var_os("JOLT_SYS_LIB_DIR") {
+        // Prebuilt Jolt and JoltC, these have to be built with the same options as the enabled features
+        println!(
+            "cargo:rustc-link-search=native={}",
+            PathBuf::from(lib_dir).display()
+        );
+        println!("cargo:rustc-link-lib=Jolt");
+        println!("cargo:rustc-link-lib=JoltC");
+    } else if !link_pkg_config() {
+        build_from_source();
+    }
+
+    generate_bindings();
+
+    println!("cargo:rerun-if-changed=JoltC/JoltPhysicsC.h");
+    println!("cargo:rerun-if-changed=CMakeLists.txt");
+}
+
+#[cfg(feature = "pkg-config")]
+fn link_pkg_config() -> bool {
+    // JoltC links Jolt itself, so only JoltC has to be found
+    match pkg_config::Config::new().probe("joltc") {
+        Ok(_) => true,
+        Err(e) => {
+            println!("cargo:warning=pkg-config couldn't find joltc ({e}), building from source");
+            false
+        }
+    }
+}
+
+#[cfg(not(feature = "pkg-config"))]
+fn link_pkg_config() -> bool {
+    false
+}
+
+fn build_from_source() {
     let mut cfg = cmake::Config::new("./");
 
-    let profile = match &std::env::var("PROFILE").unwrap()[..] {
-        "debug" => "Debug",
-        "release" | "bench" => "Release",
-        unknown => {
-            eprintln!(
-                "Warning: unknown Rust profile={}; defaulting to a release build.",
-                unknown
-            );
-            "Release"
+    for (feature, options, _) in FEATURES {
+        let value = if feature_enabled(feature) {
+            "ON"
+        } else {
+            "OFF"
+        };
+        for option in options.iter() {
+            cfg.define(option, value);
         }
+    }
+
+    // Jolt's Debug configuration defines _DEBUG, which turns on asserts and with them changes the
+    // layout of JPC_MotionProperties. Asserts are controlled by the `asserts` feature instead, so
+    // debug builds of this crate use the Release configuration as well.
+    let profile = "Release";
+
+    // Visual Studio generators are multi-config and put the libraries in a per-config directory
+    let msvc = env::var("CARGO_CFG_TARGET_ENV").as_deref() == Ok("msvc");
+    let (build_target, lib_dir) = if msvc {
+        ("ALL_BUILD", format!("build/{profile}"))
+    } else {
+        ("all", "build".to_string())
     };
 
     let dst = cfg
@@ -21,18 +109,27 @@ fn main() {
         .define("USE_STATIC_MSVC_RUNTIME_LIBRARY", "OFF")
         // .build_target("JoltC")
         .profile(profile)
-        .build_target("ALL_BUILD")
+        .build_target(build_target)
         .build();
 
     println!(
         "cargo:rustc-link-search=native={}",
-        dst.join("build/").join(profile).display()
+        dst.join(lib_dir).display()
     );
     println!("cargo:rustc-link-lib=Jolt");
     println!("cargo:rustc-link-lib=JoltC");
+}
+
+#[cfg(feature = "bindgen")]
+fn generate_bindings() {
+    let defines = FEATURES
+        .iter()
+        .filter(|(feature, _, _)| feature_enabled(feature))
+        .map(|(_, _, define)| format!("-D{define}"));
 
     let bindings = bindgen::Builder::default()
         .header("JoltC/JoltPhysicsC.h")
+        .clang_args(defines)
         .allowlist_item("JPC_+.*")
         .default_enum_style(bindgen::EnumVariation::Consts)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
@@ -45,6 +142,56 @@ fn main() {
         .write_to_file(out_path.join("bindings.rs"))
         .expect("Couldn't write bindings!");
 
-    println!("cargo:rerun-if-changed=JoltC/JoltPhysicsC.h");
-    println!("cargo:rerun-if-changed=CMakeLists.txt");
+    // Refreshes the pregenerated bindings used when the `bindgen` feature is off
+    if env::var_os("JOLT_SYS_UPDATE_BINDINGS").is_some() {
+        check_pregenerated_features();
+        bindings
+            .write_to_file(PathBuf::from("src/bindings").join(pregenerated_bindings()))
+            .expect("Couldn't update the pregenerated bindings!");
+    }
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings() {
+    check_pregenerated_features();
+
+    println!(
+        "cargo:rerun-if-changed=src/bindings/{}",
+        pregenerated_bindings()
+    );
+}
+
+/// The pregenerated bindings are generated with the default features for 64-bit targets, once per
+/// C++ ABI and precision. Panics for configurations that would need different bindings.
+fn check_pregenerated_features() {
+    if env::var("CARGO_CFG_TARGET_POINTER_WIDTH").as_deref() != Ok("64") {
+        panic!(
+            "The pregenerated bindings only support 64-bit targets, enable the `bindgen` feature"
+        );
+    }
+
+    if feature_enabled("ASSERTS") {
+        panic!("The `asserts` feature changes the layout of JPC_MotionProperties, enable the `bindgen` feature to use it");
+    }
+
+    // The declarations would still be there, but fail to link
+    if !feature_enabled("DEBUG_RENDERER") {
+        panic!("The pregenerated bindings include the debug renderer, enable the `bindgen` feature to build without it");
+    }
+}
+
+fn pregenerated_bindings() -> String {
+    // The vtable layouts in the header differ between MSVC and the Itanium C++ ABI used elsewhere
+    let abi = if env::var("CARGO_CFG_TARGET_ENV").as_deref() == Ok("msvc") {
+        "msvc"
+    } else {
+        "itanium"
+    };
+    let precision = if feature_enabled("DOUBLE_PRECISION") {
+        "double_precision.rs"
+    } else {
+        "single_precision.rs"
+    };
+
+    format!("{abi}/{precision}")
 }
@@ -0,0 +1,2978 @@
+// Pregenerated from JoltC/JoltPhysicsC.h with the default features, used when the `bindgen`
+// feature is off. Covers MSVC targets.
+// Refresh with `JOLT_SYS_UPDATE_BINDINGS=1 cargo build -p jolt-sys --features bindgen`.
+
+pub type JPC_Real = f64;
+pub const JPC_JobSystemConstants_JPC_MAX_PHYSICS_JOBS: JPC_JobSystemConstants = 2048;
+pub const JPC_JobSystemConstants_JPC_MAX_PHYSICS_BARRIERS: JPC_JobSystemConstants = 8;
+pub type JPC_JobSystemConstants = ::std::os::raw::c_int;
+pub type JPC_PhysicsUpdateError = u8;
+pub const JPC_EPhysicsUpdateError_JPC_PHYSICS_UPDATE_NO_ERROR: JPC_EPhysicsUpdateError = 0;
+pub const JPC_EPhysicsUpdateError_JPC_PHYSICS_UPDATE_MANIFOLD_CACHE_FULL: JPC_EPhysicsUpdateError =
+    1;
+pub const JPC_EPhysicsUpdateError_JPC_PHYSICS_UPDATE_BODY_PAIR_CACHE_FULL: JPC_EPhysicsUpdateError =
+    2;
+pub const JPC_EPhysicsUpdateError_JPC_PHYSICS_UPDATE_CONTACT_CONSTRAINTS_FULL:
+    JPC_EPhysicsUpdateError = 4;
+pub type JPC_EPhysicsUpdateError = ::std::os::raw::c_int;
+pub type JPC_TempAllocatorStrategy = u8;
+pub const JPC_ETempAllocatorStrategy_JPC_TEMP_ALLOCATOR_FIXED: JPC_ETempAllocatorStrategy = 0;
+pub const JPC_ETempAllocatorStrategy_JPC_TEMP_ALLOCATOR_FIXED_MALLOC_FALLBACK:
+    JPC_ETempAllocatorStrategy = 1;
+pub const JPC_ETempAllocatorStrategy_JPC_TEMP_ALLOCATOR_GROWABLE: JPC_ETempAllocatorStrategy = 2;
+pub type JPC_ETempAllocatorStrategy = ::std::os::raw::c_int;
+pub type JPC_ShapeType = u8;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_CONVEX: JPC_EShapeType = 0;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_COMPOUND: JPC_EShapeType = 1;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_DECORATED: JPC_EShapeType = 2;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_MESH: JPC_EShapeType = 3;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_HEIGHT_FIELD: JPC_EShapeType = 4;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_SOFT_BODY: JPC_EShapeType = 5;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_USER1: JPC_EShapeType = 6;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_USER2: JPC_EShapeType = 7;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_USER3: JPC_EShapeType = 8;
+pub const JPC_EShapeType_JPC_SHAPE_TYPE_USER4: JPC_EShapeType = 9;
+pub type JPC_EShapeType = ::std::os::raw::c_int;
+pub type JPC_ShapeSubType = u8;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_SPHERE: JPC_EShapeSubType = 0;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_BOX: JPC_EShapeSubType = 1;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_TRIANGLE: JPC_EShapeSubType = 2;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_CAPSULE: JPC_EShapeSubType = 3;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_TAPERED_CAPSULE: JPC_EShapeSubType = 4;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_CYLINDER: JPC_EShapeSubType = 5;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_CONVEX_HULL: JPC_EShapeSubType = 6;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_STATIC_COMPOUND: JPC_EShapeSubType = 7;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_MUTABLE_COMPOUND: JPC_EShapeSubType = 8;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_ROTATED_TRANSLATED: JPC_EShapeSubType = 9;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_SCALED: JPC_EShapeSubType = 10;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_OFFSET_CENTER_OF_MASS: JPC_EShapeSubType = 11;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_MESH: JPC_EShapeSubType = 12;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_HEIGHT_FIELD: JPC_EShapeSubType = 13;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_SOFT_BODY: JPC_EShapeSubType = 14;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER1: JPC_EShapeSubType = 15;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER2: JPC_EShapeSubType = 16;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER3: JPC_EShapeSubType = 17;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER4: JPC_EShapeSubType = 18;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER5: JPC_EShapeSubType = 19;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER6: JPC_EShapeSubType = 20;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER7: JPC_EShapeSubType = 21;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER8: JPC_EShapeSubType = 22;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX1: JPC_EShapeSubType = 23;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX2: JPC_EShapeSubType = 24;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX3: JPC_EShapeSubType = 25;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX4: JPC_EShapeSubType = 26;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX5: JPC_EShapeSubType = 27;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX6: JPC_EShapeSubType = 28;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX7: JPC_EShapeSubType = 29;
+pub const JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX8: JPC_EShapeSubType = 30;
+pub type JPC_EShapeSubType = ::std::os::raw::c_int;
+pub const JPC_ConstraintType_JPC_CONSTRAINT_TYPE_CONSTRAINT: JPC_ConstraintType = 0;
+pub const JPC_ConstraintType_JPC_CONSTRAINT_TYPE_TWO_BODY_CONSTRAINT: JPC_ConstraintType = 1;
+pub const JPC_ConstraintType__JPC_CONSTRAINT_TYPE_FORCEU32: JPC_ConstraintType = 2147483647;
+pub type JPC_ConstraintType = ::std::os::raw::c_int;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_FIXED: JPC_ConstraintSubType = 0;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_POINT: JPC_ConstraintSubType = 1;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_HINGE: JPC_ConstraintSubType = 2;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_SLIDER: JPC_ConstraintSubType = 3;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_DISTANCE: JPC_ConstraintSubType = 4;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_CONE: JPC_ConstraintSubType = 5;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_SWING_TWIST: JPC_ConstraintSubType = 6;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_SIX_DOF: JPC_ConstraintSubType = 7;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_PATH: JPC_ConstraintSubType = 8;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_VEHICLE: JPC_ConstraintSubType = 9;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_RACK_AND_PINION: JPC_ConstraintSubType = 10;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_GEAR: JPC_ConstraintSubType = 11;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_PULLEY: JPC_ConstraintSubType = 12;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER1: JPC_ConstraintSubType = 13;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER2: JPC_ConstraintSubType = 14;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER3: JPC_ConstraintSubType = 15;
+pub const JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER4: JPC_ConstraintSubType = 16;
+pub const JPC_ConstraintSubType__JPC_CONSTRAINT_SUB_TYPE_FORCEU32: JPC_ConstraintSubType =
+    2147483647;
+pub type JPC_ConstraintSubType = ::std::os::raw::c_int;
+pub const JPC_ConstraintSpace_JPC_CONSTRAINT_SPACE_LOCAL_TO_BODY_COM: JPC_ConstraintSpace = 0;
+pub const JPC_ConstraintSpace_JPC_CONSTRAINT_SPACE_WORLD_SPACE: JPC_ConstraintSpace = 1;
+pub const JPC_ConstraintSpace__JPC_CONSTRAINT_SPACE_FORCEU32: JPC_ConstraintSpace = 2147483647;
+pub type JPC_ConstraintSpace = ::std::os::raw::c_int;
+pub type JPC_MotionType = u8;
+pub const JPC_EMotionType_JPC_MOTION_TYPE_STATIC: JPC_EMotionType = 0;
+pub const JPC_EMotionType_JPC_MOTION_TYPE_KINEMATIC: JPC_EMotionType = 1;
+pub const JPC_EMotionType_JPC_MOTION_TYPE_DYNAMIC: JPC_EMotionType = 2;
+pub type JPC_EMotionType = ::std::os::raw::c_int;
+pub type JPC_MotionQuality = u8;
+pub const JPC_EMotionQuality_JPC_MOTION_QUALITY_DISCRETE: JPC_EMotionQuality = 0;
+pub const JPC_EMotionQuality_JPC_MOTION_QUALITY_LINEAR_CAST: JPC_EMotionQuality = 1;
+pub type JPC_EMotionQuality = ::std::os::raw::c_int;
+pub type JPC_OverrideMassProperties = u8;
+pub const JPC_EOverrideMassProperties_JPC_OVERRIDE_MASS_PROPS_CALC_MASS_INERTIA:
+    JPC_EOverrideMassProperties = 0;
+pub const JPC_EOverrideMassProperties_JPC_OVERRIDE_MASS_PROPS_CALC_INERTIA:
+    JPC_EOverrideMassProperties = 1;
+pub const JPC_EOverrideMassProperties_JPC_OVERRIDE_MASS_PROPS_MASS_INERTIA_PROVIDED:
+    JPC_EOverrideMassProperties = 2;
+pub type JPC_EOverrideMassProperties = ::std::os::raw::c_int;
+pub const JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_ON_GROUND: JPC_CharacterGroundState =
+    0;
+pub const JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_ON_STEEP_GROUND:
+    JPC_CharacterGroundState = 1;
+pub const JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_NOT_SUPPORTED:
+    JPC_CharacterGroundState = 2;
+pub const JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_IN_AIR: JPC_CharacterGroundState = 3;
+pub const JPC_CharacterGroundState__JPC_CHARACTER_GROUND_FORCEU32: JPC_CharacterGroundState =
+    2147483647;
+pub type JPC_CharacterGroundState = ::std::os::raw::c_int;
+pub const JPC_Activation_JPC_ACTIVATION_ACTIVATE: JPC_Activation = 0;
+pub const JPC_Activation_JPC_ACTIVATION_DONT_ACTIVATE: JPC_Activation = 1;
+pub const JPC_Activation__JPC_ACTIVATION_FORCEU32: JPC_Activation = 2147483647;
+pub type JPC_Activation = ::std::os::raw::c_int;
+pub const JPC_ValidateResult_JPC_VALIDATE_RESULT_ACCEPT_ALL_CONTACTS: JPC_ValidateResult = 0;
+pub const JPC_ValidateResult_JPC_VALIDATE_RESULT_ACCEPT_CONTACT: JPC_ValidateResult = 1;
+pub const JPC_ValidateResult_JPC_VALIDATE_RESULT_REJECT_CONTACT: JPC_ValidateResult = 2;
+pub const JPC_ValidateResult_JPC_VALIDATE_RESULT_REJECT_ALL_CONTACTS: JPC_ValidateResult = 3;
+pub const JPC_ValidateResult__JPC_VALIDATE_RESULT_FORCEU32: JPC_ValidateResult = 2147483647;
+pub type JPC_ValidateResult = ::std::os::raw::c_int;
+pub type JPC_BackFaceMode = u8;
+pub const JPC_EBackFaceMode_JPC_BACK_FACE_IGNORE: JPC_EBackFaceMode = 0;
+pub const JPC_EBackFaceMode_JPC_BACK_FACE_COLLIDE: JPC_EBackFaceMode = 1;
+pub type JPC_EBackFaceMode = ::std::os::raw::c_int;
+pub type JPC_Features = u32;
+pub const JPC_EFeatures_JPC_FEATURE_DOUBLE_PRECISION: JPC_EFeatures = 1;
+pub const JPC_EFeatures_JPC_FEATURE_NEON: JPC_EFeatures = 2;
+pub const JPC_EFeatures_JPC_FEATURE_SSE: JPC_EFeatures = 4;
+pub const JPC_EFeatures_JPC_FEATURE_SSE4_1: JPC_EFeatures = 8;
+pub const JPC_EFeatures_JPC_FEATURE_SSE4_2: JPC_EFeatures = 16;
+pub const JPC_EFeatures_JPC_FEATURE_AVX: JPC_EFeatures = 32;
+pub const JPC_EFeatures_JPC_FEATURE_AVX2: JPC_EFeatures = 64;
+pub const JPC_EFeatures_JPC_FEATURE_AVX512: JPC_EFeatures = 128;
+pub const JPC_EFeatures_JPC_FEATURE_F16C: JPC_EFeatures = 256;
+pub const JPC_EFeatures_JPC_FEATURE_LZCNT: JPC_EFeatures = 512;
+pub const JPC_EFeatures_JPC_FEATURE_TZCNT: JPC_EFeatures = 1024;
+pub const JPC_EFeatures_JPC_FEATURE_FMADD: JPC_EFeatures = 2048;
+pub const JPC_EFeatures_JPC_FEATURE_PLATFORM_DETERMINISTIC: JPC_EFeatures = 4096;
+pub const JPC_EFeatures_JPC_FEATURE_FLOATING_POINT_EXCEPTIONS: JPC_EFeatures = 8192;
+pub const JPC_EFeatures_JPC_FEATURE_DEBUG: JPC_EFeatures = 16384;
+pub const JPC_EFeatures_JPC_FEATURE_DEBUG_RENDERER: JPC_EFeatures = 32768;
+pub const JPC_EFeatures_JPC_FEATURE_PROFILER: JPC_EFeatures = 65536;
+pub const JPC_EFeatures_JPC_FEATURE_ASSERTS: JPC_EFeatures = 131072;
+pub type JPC_EFeatures = ::std::os::raw::c_int;
+pub type JPC_AllowedDOFs = u8;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_NONE: JPC_EAllowedDOFs = 0;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_ALL: JPC_EAllowedDOFs = 63;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_TRANSLATIONX: JPC_EAllowedDOFs = 1;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_TRANSLATIONY: JPC_EAllowedDOFs = 2;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_TRANSLATIONZ: JPC_EAllowedDOFs = 4;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_ROTATIONX: JPC_EAllowedDOFs = 8;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_ROTATIONY: JPC_EAllowedDOFs = 16;
+pub const JPC_EAllowedDOFs_JPC_ALLOWED_DOFS_ROTATIONZ: JPC_EAllowedDOFs = 32;
+pub type JPC_EAllowedDOFs = ::std::os::raw::c_int;
+pub type JPC_BodyType = u8;
+pub const JPC_EBodyType_JPC_BODY_TYPE_RIGIDBODY: JPC_EBodyType = 0;
+pub const JPC_EBodyType_JPC_BODY_TYPE_SOFTBODY: JPC_EBodyType = 1;
+pub type JPC_EBodyType = ::std::os::raw::c_int;
+pub const JPC_DebugRendererResult_JPC_DEBUGRENDERER_SUCCESS: JPC_DebugRendererResult = 0;
+pub const JPC_DebugRendererResult_JPC_DEBUGRENDERER_DUPLICATE_SINGLETON: JPC_DebugRendererResult =
+    1;
+pub const JPC_DebugRendererResult_JPC_DEBUGRENDERER_MISSING_SINGLETON: JPC_DebugRendererResult = 2;
+pub const JPC_DebugRendererResult_JPC_DEBUGRENDERER_INCOMPLETE_IMPL: JPC_DebugRendererResult = 3;
+pub type JPC_DebugRendererResult = ::std::os::raw::c_int;
+pub const JPC_CullMode_JPC_CULL_BACK_FACE: JPC_CullMode = 0;
+pub const JPC_CullMode_JPC_CULL_FRONT_FACE: JPC_CullMode = 1;
+pub const JPC_CullMode_JPC_CULLING_OFF: JPC_CullMode = 2;
+pub const JPC_CullMode__JPC_CULLING_FORCEU32: JPC_CullMode = 2147483647;
+pub type JPC_CullMode = ::std::os::raw::c_int;
+pub const JPC_CastShadow_JPC_CAST_SHADOW_ON: JPC_CastShadow = 0;
+pub const JPC_CastShadow_JPC_CAST_SHADOW_OFF: JPC_CastShadow = 1;
+pub const JPC_CastShadow__JPC_CAST_SHADOW_FORCEU32: JPC_CastShadow = 2147483647;
+pub type JPC_CastShadow = ::std::os::raw::c_int;
+pub const JPC_DrawMode_JPC_DRAW_MODE_SOLID: JPC_DrawMode = 0;
+pub const JPC_DrawMode_JPC_DRAW_MODE_WIREFRAME: JPC_DrawMode = 1;
+pub const JPC_DrawMode__JPC_DRAW_MODE_FORCEU32: JPC_DrawMode = 2147483647;
+pub type JPC_DrawMode = ::std::os::raw::c_int;
+pub const JPC_ShapeColor_JPC_INSTANCE_COLOR: JPC_ShapeColor = 0;
+pub const JPC_ShapeColor_JPC_SHAPE_TYPE_COLOR: JPC_ShapeColor = 1;
+pub const JPC_ShapeColor_JPC_MOTION_TYPE_COLOR: JPC_ShapeColor = 2;
+pub const JPC_ShapeColor_JPC_SLEEP_COLOR: JPC_ShapeColor = 3;
+pub const JPC_ShapeColor_JPC_ISLAND_COLOR: JPC_ShapeColor = 4;
+pub const JPC_ShapeColor_JPC_MATERIAL_COLOR: JPC_ShapeColor = 5;
+pub type JPC_ShapeColor = ::std::os::raw::c_int;
+pub type JPC_ObjectLayer = u16;
+pub type JPC_BroadPhaseLayer = u8;
+pub type JPC_BodyID = u32;
+pub type JPC_SubShapeID = u32;
+pub type JPC_CollisionGroupID = u32;
+pub type JPC_CollisionSubGroupID = u32;
+pub type JPC_AllocateFunction =
+    ::std::option::Option<unsafe extern "C" fn(in_size: usize) -> *mut ::std::os::raw::c_void>;
+pub type JPC_FreeFunction =
+    ::std::option::Option<unsafe extern "C" fn(in_block: *mut ::std::os::raw::c_void)>;
+pub type JPC_AlignedAllocateFunction = ::std::option::Option<
+    unsafe extern "C" fn(in_size: usize, in_alignment: usize) -> *mut ::std::os::raw::c_void,
+>;
+pub type JPC_AlignedFreeFunction =
+    ::std::option::Option<unsafe extern "C" fn(in_block: *mut ::std::os::raw::c_void)>;
+pub type JPC_AssertFailedFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        inExpression: *const ::std::os::raw::c_char,
+        inMessage: *const ::std::os::raw::c_char,
+        inFile: *const ::std::os::raw::c_char,
+        inLine: ::std::os::raw::c_uint,
+    ) -> bool,
+>;
+pub type JPC_TraceFunction =
+    ::std::option::Option<unsafe extern "C" fn(in_message: *const ::std::os::raw::c_char)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_TempAllocator {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_JobSystem {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_Job {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyInterface {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_AddBodiesState {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyLockInterface {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_NarrowPhaseQuery {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ConvexShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BoxShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_SphereShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_TriangleShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CapsuleShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_TaperedCapsuleShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CylinderShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ConvexHullShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_HeightFieldShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_MeshShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_DecoratedShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CompoundShapeSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CharacterContactSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ConstraintSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_TwoBodyConstraintSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_FixedConstraintSettings {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_PhysicsSystem {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_SharedMutex {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_Shape {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_Constraint {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_PhysicsMaterial {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_GroupFilter {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_Character {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CharacterVirtual {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyDrawFilter {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_DebugRenderer_TriangleBatch {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_DebugRenderer_Primitive {
+    _unused: [u8; 0],
+}
+pub type JPC_QueueJobFunction = ::std::option::Option<
+    unsafe extern "C" fn(in_user_data: *mut ::std::os::raw::c_void, in_job: *mut JPC_Job),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_TempAllocatorStats {
+    pub capacity: u32,
+    pub usage: u32,
+    pub high_water_mark: u32,
+    pub fallback_allocations: u32,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_MassProperties {
+    pub mass: f32,
+    pub __bindgen_padding_0: [u32; 3],
+    pub inertia: [f32; 16],
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_MotionProperties {
+    pub linear_velocity: [f32; 4],
+    pub angular_velocity: [f32; 4],
+    pub inv_inertia_diagnonal: [f32; 4],
+    pub inertia_rotation: [f32; 4],
+    pub force: [f32; 3],
+    pub torque: [f32; 3],
+    pub inv_mass: f32,
+    pub linear_damping: f32,
+    pub angular_daming: f32,
+    pub max_linear_velocity: f32,
+    pub max_angular_velocity: f32,
+    pub gravity_factor: f32,
+    pub index_in_active_bodies: u32,
+    pub island_index: u32,
+    pub motion_quality: JPC_MotionQuality,
+    pub allow_sleeping: bool,
+    pub allowed_dofs: JPC_AllowedDOFs,
+    pub num_velocity_steps_override: u8,
+    pub num_position_steps_override: u8,
+    pub __bindgen_padding_0: [u8; 3],
+    pub reserved: [u8; 76],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CollisionGroup {
+    pub filter: *const JPC_GroupFilter,
+    pub group_id: JPC_CollisionGroupID,
+    pub sub_group_id: JPC_CollisionSubGroupID,
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyCreationSettings {
+    pub position: [JPC_Real; 4],
+    pub rotation: [f32; 4],
+    pub linear_velocity: [f32; 4],
+    pub angular_velocity: [f32; 4],
+    pub user_data: u64,
+    pub object_layer: JPC_ObjectLayer,
+    pub collision_group: JPC_CollisionGroup,
+    pub motion_type: JPC_MotionType,
+    pub allowed_dofs: JPC_AllowedDOFs,
+    pub allow_dynamic_or_kinematic: bool,
+    pub is_sensor: bool,
+    pub collide_kinematic_vs_non_dynamic: bool,
+    pub use_manifold_reduction: bool,
+    pub apply_gyroscopic_force: bool,
+    pub motion_quality: JPC_MotionQuality,
+    pub enhanced_internal_edge_removal: bool,
+    pub allow_sleeping: bool,
+    pub friction: f32,
+    pub restitution: f32,
+    pub linear_damping: f32,
+    pub angular_damping: f32,
+    pub max_linear_velocity: f32,
+    pub max_angular_velocity: f32,
+    pub gravity_factor: f32,
+    pub num_velocity_steps_override: u32,
+    pub num_position_steps_override: u32,
+    pub override_mass_properties: JPC_OverrideMassProperties,
+    pub inertia_multiplier: f32,
+    pub __bindgen_padding_0: u64,
+    pub mass_properties_override: JPC_MassProperties,
+    pub reserved: *const ::std::os::raw::c_void,
+    pub shape: *const JPC_Shape,
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_Body {
+    pub position: [JPC_Real; 4],
+    pub rotation: [f32; 4],
+    pub bounds_min: [f32; 4],
+    pub bounds_max: [f32; 4],
+    pub shape: *const JPC_Shape,
+    pub motion_properties: *mut JPC_MotionProperties,
+    pub user_data: u64,
+    pub collision_group: JPC_CollisionGroup,
+    pub friction: f32,
+    pub restitution: f32,
+    pub id: JPC_BodyID,
+    pub object_layer: JPC_ObjectLayer,
+    pub body_type: JPC_BodyType,
+    pub broad_phase_layer: JPC_BroadPhaseLayer,
+    pub motion_type: JPC_MotionType,
+    pub flags: u8,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CharacterBaseSettings {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub __bindgen_padding_0: u64,
+    pub up: [f32; 4],
+    pub supporting_volume: [f32; 4],
+    pub max_slope_angle: f32,
+    pub shape: *const JPC_Shape,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CharacterSettings {
+    pub base: JPC_CharacterBaseSettings,
+    pub layer: JPC_ObjectLayer,
+    pub mass: f32,
+    pub friction: f32,
+    pub gravity_factor: f32,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CharacterVirtualSettings {
+    pub base: JPC_CharacterBaseSettings,
+    pub mass: f32,
+    pub max_strength: f32,
+    pub __bindgen_padding_0: u64,
+    pub shape_offset: [f32; 4],
+    pub back_face_mode: JPC_BackFaceMode,
+    pub predictive_contact_distance: f32,
+    pub max_collision_iterations: u32,
+    pub max_constraint_iterations: u32,
+    pub min_time_remaining: f32,
+    pub collision_tolerance: f32,
+    pub character_padding: f32,
+    pub max_num_hits: u32,
+    pub hit_reduction_cos_max_angle: f32,
+    pub penetration_recovery_speed: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_SubShapeIDCreator {
+    pub id: JPC_SubShapeID,
+    pub current_bit: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_SubShapeIDPair__bindgen_ty_1 {
+    pub body_id: JPC_BodyID,
+    pub sub_shape_id: JPC_SubShapeID,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_SubShapeIDPair__bindgen_ty_2 {
+    pub body_id: JPC_BodyID,
+    pub sub_shape_id: JPC_SubShapeID,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_SubShapeIDPair {
+    pub first: JPC_SubShapeIDPair__bindgen_ty_1,
+    pub second: JPC_SubShapeIDPair__bindgen_ty_2,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ContactManifold__bindgen_ty_1 {
+    pub num_points: u32,
+    pub __bindgen_padding_0: [u32; 3],
+    pub points: [[f32; 4]; 64],
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ContactManifold__bindgen_ty_2 {
+    pub num_points: u32,
+    pub __bindgen_padding_0: [u32; 3],
+    pub points: [[f32; 4]; 64],
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ContactManifold {
+    pub base_offset: [JPC_Real; 4],
+    pub normal: [f32; 4],
+    pub penetration_depth: f32,
+    pub shape1_sub_shape_id: JPC_SubShapeID,
+    pub shape2_sub_shape_id: JPC_SubShapeID,
+    pub __bindgen_padding_0: u32,
+    pub shape1_relative_contact: JPC_ContactManifold__bindgen_ty_1,
+    pub shape2_relative_contact: JPC_ContactManifold__bindgen_ty_2,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ContactSettings {
+    pub combined_friction: f32,
+    pub combined_restitution: f32,
+    pub inv_mass_scale1: f32,
+    pub inv_inertia_scale1: f32,
+    pub inv_mass_scale2: f32,
+    pub inv_inertia_scale2: f32,
+    pub is_sensor: bool,
+    pub __bindgen_padding_0: [u8; 7],
+    pub relative_linear_surface_velocity: [f32; 4],
+    pub relative_angular_surface_velocity: [f32; 4],
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CollideShapeResult__bindgen_ty_1 {
+    pub num_points: u32,
+    pub __bindgen_padding_0: [u32; 3],
+    pub points: [[f32; 4]; 32],
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CollideShapeResult__bindgen_ty_2 {
+    pub num_points: u32,
+    pub __bindgen_padding_0: [u32; 3],
+    pub points: [[f32; 4]; 32],
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CollideShapeResult {
+    pub shape1_contact_point: [f32; 4],
+    pub shape2_contact_point: [f32; 4],
+    pub penetration_axis: [f32; 4],
+    pub penetration_depth: f32,
+    pub shape1_sub_shape_id: JPC_SubShapeID,
+    pub shape2_sub_shape_id: JPC_SubShapeID,
+    pub body2_id: JPC_BodyID,
+    pub shape1_face: JPC_CollideShapeResult__bindgen_ty_1,
+    pub shape2_face: JPC_CollideShapeResult__bindgen_ty_2,
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_TransformedShape {
+    pub shape_position_com: [JPC_Real; 4],
+    pub shape_rotation: [f32; 4],
+    pub shape: *const JPC_Shape,
+    pub shape_scale: [f32; 3],
+    pub body_id: JPC_BodyID,
+    pub sub_shape_id_creator: JPC_SubShapeIDCreator,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyLockRead {
+    pub lock_interface: *const JPC_BodyLockInterface,
+    pub mutex: *mut JPC_SharedMutex,
+    pub body: *const JPC_Body,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyLockWrite {
+    pub lock_interface: *const JPC_BodyLockInterface,
+    pub mutex: *mut JPC_SharedMutex,
+    pub body: *mut JPC_Body,
+}
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_RRayCast {
+    pub origin: [JPC_Real; 4],
+    pub direction: [f32; 4],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_RayCastResult {
+    pub body_id: JPC_BodyID,
+    pub fraction: f32,
+    pub sub_shape_id: JPC_SubShapeID,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_RayCastSettings {
+    pub back_face_mode_triangles: JPC_BackFaceMode,
+    pub back_face_mode_convex: JPC_BackFaceMode,
+    pub treat_convex_as_solid: bool,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_AABox {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_Color__bindgen_ty_1 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union JPC_Color {
+    pub u32: u32,
+    pub __bindgen_anon_1: JPC_Color__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct JPC_DebugRenderer_Vertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub uv: [f32; 2],
+    pub color: JPC_Color,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct JPC_DebugRenderer_Triangle {
+    pub v: [JPC_DebugRenderer_Vertex; 3],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_DebugRenderer_LOD {
+    pub batch: *mut JPC_DebugRenderer_TriangleBatch,
+    pub distance: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_DebugRenderer_Geometry {
+    pub LODs: *mut JPC_DebugRenderer_LOD,
+    pub num_LODs: u64,
+    pub bounds: *mut JPC_AABox,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyManager_DrawSettings {
+    pub get_support_func: bool,
+    pub get_support_dir: bool,
+    pub get_supporting_face: bool,
+    pub shape: bool,
+    pub shape_wireframe: bool,
+    pub shape_color: JPC_ShapeColor,
+    pub bounding_box: bool,
+    pub center_of_mass_transform: bool,
+    pub world_transform: bool,
+    pub velocity: bool,
+    pub mass_and_inertia: bool,
+    pub sleep_stats: bool,
+}
+pub type JPC_BodyDrawFilterFunc =
+    ::std::option::Option<unsafe extern "C" fn(arg0: *const JPC_Body) -> bool>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BroadPhaseLayerInterfaceVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub GetNumBroadPhaseLayers:
+        ::std::option::Option<unsafe extern "C" fn(in_self: *const ::std::os::raw::c_void) -> u32>,
+    pub GetBroadPhaseLayer: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            out_layer: *mut JPC_BroadPhaseLayer,
+            in_layer: JPC_ObjectLayer,
+        ) -> *const JPC_BroadPhaseLayer,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ObjectVsBroadPhaseLayerFilterVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub ShouldCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_layer1: JPC_ObjectLayer,
+            in_layer2: JPC_BroadPhaseLayer,
+        ) -> bool,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BroadPhaseLayerFilterVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub ShouldCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_layer: JPC_BroadPhaseLayer,
+        ) -> bool,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ObjectLayerPairFilterVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub ShouldCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_layer1: JPC_ObjectLayer,
+            in_layer2: JPC_ObjectLayer,
+        ) -> bool,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ObjectLayerFilterVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub ShouldCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_layer: JPC_ObjectLayer,
+        ) -> bool,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyActivationListenerVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub OnBodyActivated: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_body_id: *const JPC_BodyID,
+            in_user_data: u64,
+        ),
+    >,
+    pub OnBodyDeactivated: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_body_id: *const JPC_BodyID,
+            in_user_data: u64,
+        ),
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_BodyFilterVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub ShouldCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_body_id: *const JPC_BodyID,
+        ) -> bool,
+    >,
+    pub ShouldCollideLocked: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_body: *const JPC_Body,
+        ) -> bool,
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ShapeFilterVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub ShouldCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_shape: *const JPC_Shape,
+            in_sub_shape_id: *const JPC_SubShapeID,
+        ) -> bool,
+    >,
+    pub PairShouldCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_shape1: *const JPC_Shape,
+            in_sub_shape_id1: *const JPC_SubShapeID,
+            in_shape2: *const JPC_Shape,
+            in_sub_shape_id2: *const JPC_SubShapeID,
+        ) -> bool,
+    >,
+    pub bodyId2: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_PhysicsStepListenerVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub OnStep: ::std::option::Option<
+        unsafe extern "C" fn(in_delta_time: f32, in_physics_system: *mut JPC_PhysicsSystem),
+    >,
+}
+pub type JPC_PhysicsStepListener = JPC_PhysicsStepListenerVTable;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_CharacterContactListenerVTable {
+    pub __vtable_header: [*const ::std::os::raw::c_void; 1],
+    pub OnAdjustBodyVelocity: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_character: *const JPC_CharacterVirtual,
+            in_body2: *const JPC_Body,
+            io_linear_velocity: *const f32,
+            io_angular_velocity: *const f32,
+        ),
+    >,
+    pub OnContactValidate: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_character: *const JPC_CharacterVirtual,
+            in_body2: *const JPC_Body,
+            sub_shape_id: *const JPC_SubShapeID,
+        ) -> bool,
+    >,
+    pub OnContactAdded: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_character: *const JPC_CharacterVirtual,
+            in_body2: *const JPC_Body,
+            sub_shape_id: *const JPC_SubShapeID,
+            contact_position: *const JPC_Real,
+            contact_normal: *const f32,
+            io_settings: *mut JPC_CharacterContactSettings,
+        ),
+    >,
+    pub OnContactSolve: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_character: *const JPC_CharacterVirtual,
+            in_body2: *const JPC_Body,
+            sub_shape_id: *const JPC_SubShapeID,
+            contact_position: *const JPC_Real,
+            contact_normal: *const f32,
+            contact_velocity: *const f32,
+            contact_material: *const JPC_PhysicsMaterial,
+            character_velocity_in: *const f32,
+            character_velocity_out: *mut f32,
+        ),
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_ContactListenerVTable {
+    pub OnContactValidate: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_body1: *const JPC_Body,
+            in_body2: *const JPC_Body,
+            in_base_offset: *const JPC_Real,
+            in_collision_result: *const JPC_CollideShapeResult,
+        ) -> JPC_ValidateResult,
+    >,
+    pub OnContactAdded: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_body1: *const JPC_Body,
+            in_body2: *const JPC_Body,
+            in_manifold: *const JPC_ContactManifold,
+            io_settings: *mut JPC_ContactSettings,
+        ),
+    >,
+    pub OnContactPersisted: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_body1: *const JPC_Body,
+            in_body2: *const JPC_Body,
+            in_manifold: *const JPC_ContactManifold,
+            io_settings: *mut JPC_ContactSettings,
+        ),
+    >,
+    pub OnContactRemoved: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_sub_shape_pair: *const JPC_SubShapeIDPair,
+        ),
+    >,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_GroupFilterVTable {
+    pub CanCollide: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *const ::std::os::raw::c_void,
+            in_group1: *const JPC_CollisionGroup,
+            in_group2: *const JPC_CollisionGroup,
+        ) -> bool,
+    >,
+    pub Destroy: ::std::option::Option<unsafe extern "C" fn(in_self: *mut ::std::os::raw::c_void)>,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct JPC_DebugRendererVTable {
+    pub DrawLine: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_from: *mut JPC_Real,
+            in_to: *mut JPC_Real,
+            in_color: JPC_Color,
+        ),
+    >,
+    pub DrawTriangle: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_v1: *mut JPC_Real,
+            in_v2: *mut JPC_Real,
+            in_v3: *mut JPC_Real,
+            in_color: JPC_Color,
+        ),
+    >,
+    pub CreateTriangleBatch: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_triangles: *const JPC_DebugRenderer_Triangle,
+            in_triangle_count: u32,
+        ) -> *mut JPC_DebugRenderer_TriangleBatch,
+    >,
+    pub CreateTriangleBatchIndexed: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_vertices: *const JPC_DebugRenderer_Vertex,
+            in_vertex_count: u32,
+            in_indices: *const u32,
+            in_index_count: u32,
+        ) -> *mut JPC_DebugRenderer_TriangleBatch,
+    >,
+    pub DrawGeometry: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            inModelMatrix: *const f32,
+            inWorldSpaceBounds: *const JPC_AABox,
+            inLODScaleSq: f32,
+            in_color: JPC_Color,
+            in_geometry: *const JPC_DebugRenderer_Geometry,
+            in_cull_mode: JPC_CullMode,
+            in_cast_shadow: JPC_CastShadow,
+            in_draw_mode: JPC_DrawMode,
+        ),
+    >,
+    pub DrawText3D: ::std::option::Option<
+        unsafe extern "C" fn(
+            in_self: *mut ::std::os::raw::c_void,
+            in_position: *mut JPC_Real,
+            in_string: *const ::std::os::raw::c_char,
+            in_color: JPC_Color,
+            in_height: f32,
+        ),
+    >,
+}
+extern "C" {
+    pub fn JPC_RegisterDefaultAllocator();
+}
+extern "C" {
+    pub fn JPC_RegisterCustomAllocator(
+        in_alloc: JPC_AllocateFunction,
+        in_free: JPC_FreeFunction,
+        in_aligned_alloc: JPC_AlignedAllocateFunction,
+        in_aligned_free: JPC_AlignedFreeFunction,
+    );
+}
+extern "C" {
+    pub fn JPC_CreateFactory();
+}
+extern "C" {
+    pub fn JPC_DestroyFactory();
+}
+extern "C" {
+    pub fn JPC_RegisterTypes();
+}
+extern "C" {
+    pub fn JPC_BodyCreationSettings_SetDefault(out_settings: *mut JPC_BodyCreationSettings);
+}
+extern "C" {
+    pub fn JPC_BodyCreationSettings_Set(
+        out_settings: *mut JPC_BodyCreationSettings,
+        in_shape: *const JPC_Shape,
+        in_position: *const JPC_Real,
+        in_rotation: *const f32,
+        in_motion_type: JPC_MotionType,
+        in_layer: JPC_ObjectLayer,
+    );
+}
+extern "C" {
+    pub fn JPC_SetAssertFailedHandler(in_handler: JPC_AssertFailedFunction);
+}
+extern "C" {
+    pub fn JPC_SetTraceHandler(in_handler: JPC_TraceFunction);
+}
+extern "C" {
+    pub fn JPC_CreateDebugRendererSingleton(
+        in_debug_renderer: *mut ::std::os::raw::c_void,
+    ) -> JPC_DebugRendererResult;
+}
+extern "C" {
+    pub fn JPC_DestroyDebugRendererSingleton() -> JPC_DebugRendererResult;
+}
+extern "C" {
+    pub fn JPC_DebugRenderer_TriangleBatch_Create(
+        in_c_primitive: *const ::std::os::raw::c_void,
+    ) -> *mut JPC_DebugRenderer_TriangleBatch;
+}
+extern "C" {
+    pub fn JPC_DebugRenderer_TriangleBatch_GetPrimitive(
+        in_batch: *const JPC_DebugRenderer_TriangleBatch,
+    ) -> *const JPC_DebugRenderer_Primitive;
+}
+extern "C" {
+    pub fn JPC_DebugRenderer_TriangleBatch_AddRef(in_batch: *mut JPC_DebugRenderer_TriangleBatch);
+}
+extern "C" {
+    pub fn JPC_DebugRenderer_TriangleBatch_Release(in_batch: *mut JPC_DebugRenderer_TriangleBatch);
+}
+extern "C" {
+    pub fn JPC_DebugRenderer_TriangleBatch_GetRefCount(
+        in_batch: *const JPC_DebugRenderer_TriangleBatch,
+    ) -> u32;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetMotionQuality(
+        in_properties: *const JPC_MotionProperties,
+    ) -> JPC_MotionQuality;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetLinearVelocity(
+        in_properties: *const JPC_MotionProperties,
+        out_linear_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetLinearVelocity(
+        in_properties: *mut JPC_MotionProperties,
+        in_linear_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetLinearVelocityClamped(
+        in_properties: *mut JPC_MotionProperties,
+        in_linear_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetAngularVelocity(
+        in_properties: *const JPC_MotionProperties,
+        out_angular_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetAngularVelocity(
+        in_properties: *mut JPC_MotionProperties,
+        in_angular_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetAngularVelocityClamped(
+        in_properties: *mut JPC_MotionProperties,
+        in_angular_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_MoveKinematic(
+        in_properties: *mut JPC_MotionProperties,
+        in_delta_position: *const f32,
+        in_delta_rotation: *const f32,
+        in_delta_time: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_ClampLinearVelocity(in_properties: *mut JPC_MotionProperties);
+}
+extern "C" {
+    pub fn JPC_MotionProperties_ClampAngularVelocity(in_properties: *mut JPC_MotionProperties);
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetLinearDamping(in_properties: *const JPC_MotionProperties)
+        -> f32;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetLinearDamping(
+        in_properties: *mut JPC_MotionProperties,
+        in_linear_damping: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetAngularDamping(
+        in_properties: *const JPC_MotionProperties,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetAngularDamping(
+        in_properties: *mut JPC_MotionProperties,
+        in_angular_damping: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetGravityFactor(in_properties: *const JPC_MotionProperties)
+        -> f32;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetGravityFactor(
+        in_properties: *mut JPC_MotionProperties,
+        in_gravity_factor: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetMassProperties(
+        in_properties: *mut JPC_MotionProperties,
+        in_mass_properties: *const JPC_MassProperties,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetInverseMass(in_properties: *const JPC_MotionProperties) -> f32;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetInverseMass(
+        in_properties: *mut JPC_MotionProperties,
+        in_inv_mass: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetInverseInertiaDiagonal(
+        in_properties: *const JPC_MotionProperties,
+        out_inverse_inertia_diagonal: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetInertiaRotation(
+        in_properties: *const JPC_MotionProperties,
+        out_inertia_rotation: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetInverseInertia(
+        in_properties: *mut JPC_MotionProperties,
+        in_diagonal: *const f32,
+        in_rotation: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetLocalSpaceInverseInertia(
+        in_properties: *const JPC_MotionProperties,
+        out_matrix: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetInverseInertiaForRotation(
+        in_properties: *const JPC_MotionProperties,
+        in_rotation_matrix: *const f32,
+        out_matrix: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_MultiplyWorldSpaceInverseInertiaByVector(
+        in_properties: *const JPC_MotionProperties,
+        in_body_rotation: *const f32,
+        in_vector: *const f32,
+        out_vector: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetPointVelocityCOM(
+        in_properties: *const JPC_MotionProperties,
+        in_point_relative_to_com: *const f32,
+        out_point: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetMaxLinearVelocity(
+        in_properties: *const JPC_MotionProperties,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetMaxLinearVelocity(
+        in_properties: *mut JPC_MotionProperties,
+        in_max_linear_velocity: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_MotionProperties_GetMaxAngularVelocity(
+        in_properties: *const JPC_MotionProperties,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_MotionProperties_SetMaxAngularVelocity(
+        in_properties: *mut JPC_MotionProperties,
+        in_max_angular_velocity: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_TempAllocator_Create(in_size: u32) -> *mut JPC_TempAllocator;
+}
+extern "C" {
+    pub fn JPC_TempAllocator_CreateWithStrategy(
+        in_strategy: JPC_TempAllocatorStrategy,
+        in_size: u32,
+    ) -> *mut JPC_TempAllocator;
+}
+extern "C" {
+    pub fn JPC_TempAllocator_Destroy(in_allocator: *mut JPC_TempAllocator);
+}
+extern "C" {
+    pub fn JPC_TempAllocator_GetStats(
+        in_allocator: *const JPC_TempAllocator,
+        out_stats: *mut JPC_TempAllocatorStats,
+    );
+}
+extern "C" {
+    pub fn JPC_TempAllocator_ResetStats(in_allocator: *mut JPC_TempAllocator);
+}
+extern "C" {
+    pub fn JPC_JobSystem_Create(
+        in_max_jobs: u32,
+        in_max_barriers: u32,
+        in_num_threads: ::std::os::raw::c_int,
+    ) -> *mut JPC_JobSystem;
+}
+extern "C" {
+    pub fn JPC_JobSystem_CreateSingleThreaded(in_max_jobs: u32) -> *mut JPC_JobSystem;
+}
+extern "C" {
+    pub fn JPC_JobSystem_CreateCustom(
+        in_max_barriers: u32,
+        in_max_concurrency: u32,
+        in_queue_job: JPC_QueueJobFunction,
+        in_user_data: *mut ::std::os::raw::c_void,
+    ) -> *mut JPC_JobSystem;
+}
+extern "C" {
+    pub fn JPC_JobSystem_Destroy(in_job_system: *mut JPC_JobSystem);
+}
+extern "C" {
+    pub fn JPC_JobSystem_DestroyCustom(in_job_system: *mut JPC_JobSystem);
+}
+extern "C" {
+    pub fn JPC_Job_Execute(in_job: *mut JPC_Job);
+}
+extern "C" {
+    pub fn JPC_Job_Release(in_job: *mut JPC_Job);
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_Create(
+        in_max_bodies: u32,
+        in_num_body_mutexes: u32,
+        in_max_body_pairs: u32,
+        in_max_contact_constraints: u32,
+        in_broad_phase_layer_interface: *const ::std::os::raw::c_void,
+        in_object_vs_broad_phase_layer_filter: *const ::std::os::raw::c_void,
+        in_object_layer_pair_filter: *const ::std::os::raw::c_void,
+    ) -> *mut JPC_PhysicsSystem;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_Destroy(in_physics_system: *mut JPC_PhysicsSystem);
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_SetBodyActivationListener(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_listener: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetBodyActivationListener(
+        in_physics_system: *const JPC_PhysicsSystem,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_SetContactListener(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_listener: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetContactListener(
+        in_physics_system: *const JPC_PhysicsSystem,
+    ) -> *mut ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetNumBodies(in_physics_system: *const JPC_PhysicsSystem) -> u32;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetNumActiveBodies(in_physics_system: *const JPC_PhysicsSystem)
+        -> u32;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetMaxBodies(in_physics_system: *const JPC_PhysicsSystem) -> u32;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetGravity(
+        in_physics_system: *const JPC_PhysicsSystem,
+        out_gravity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_SetGravity(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_gravity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetBodyInterface(
+        in_physics_system: *mut JPC_PhysicsSystem,
+    ) -> *mut JPC_BodyInterface;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetBodyInterfaceNoLock(
+        in_physics_system: *mut JPC_PhysicsSystem,
+    ) -> *mut JPC_BodyInterface;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_OptimizeBroadPhase(in_physics_system: *mut JPC_PhysicsSystem);
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_AddStepListener(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_listener: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_RemoveStepListener(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_listener: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_AddConstraint(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_two_body_constraint: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_RemoveConstraint(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_two_body_constraint: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_Update(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_delta_time: f32,
+        in_collision_steps: ::std::os::raw::c_int,
+        in_temp_allocator: *mut JPC_TempAllocator,
+        in_job_system: *mut JPC_JobSystem,
+    ) -> JPC_PhysicsUpdateError;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetBodyLockInterface(
+        in_physics_system: *const JPC_PhysicsSystem,
+    ) -> *const JPC_BodyLockInterface;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetBodyLockInterfaceNoLock(
+        in_physics_system: *const JPC_PhysicsSystem,
+    ) -> *const JPC_BodyLockInterface;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetNarrowPhaseQuery(
+        in_physics_system: *const JPC_PhysicsSystem,
+    ) -> *const JPC_NarrowPhaseQuery;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetNarrowPhaseQueryNoLock(
+        in_physics_system: *const JPC_PhysicsSystem,
+    ) -> *const JPC_NarrowPhaseQuery;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetBodyIDs(
+        in_physics_system: *const JPC_PhysicsSystem,
+        in_max_body_ids: u32,
+        out_num_body_ids: *mut u32,
+        out_body_ids: *mut JPC_BodyID,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetActiveBodyIDs(
+        in_physics_system: *const JPC_PhysicsSystem,
+        in_max_body_ids: u32,
+        out_num_body_ids: *mut u32,
+        out_body_ids: *mut JPC_BodyID,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_GetBodiesUnsafe(
+        in_physics_system: *mut JPC_PhysicsSystem,
+    ) -> *mut *mut JPC_Body;
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_DrawBodies(
+        in_physics_system: *mut JPC_PhysicsSystem,
+        in_draw_settings: *const JPC_BodyManager_DrawSettings,
+        in_draw_filter: *const JPC_BodyDrawFilter,
+    );
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_DrawConstraints(in_physics_system: *mut JPC_PhysicsSystem);
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_DrawConstraintLimits(in_physics_system: *mut JPC_PhysicsSystem);
+}
+extern "C" {
+    pub fn JPC_PhysicsSystem_DrawConstraintReferenceFrame(
+        in_physics_system: *mut JPC_PhysicsSystem,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyLockInterface_LockRead(
+        in_lock_interface: *const JPC_BodyLockInterface,
+        in_body_id: JPC_BodyID,
+        out_lock: *mut JPC_BodyLockRead,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyLockInterface_UnlockRead(
+        in_lock_interface: *const JPC_BodyLockInterface,
+        io_lock: *mut JPC_BodyLockRead,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyLockInterface_LockWrite(
+        in_lock_interface: *const JPC_BodyLockInterface,
+        in_body_id: JPC_BodyID,
+        out_lock: *mut JPC_BodyLockWrite,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyLockInterface_UnlockWrite(
+        in_lock_interface: *const JPC_BodyLockInterface,
+        io_lock: *mut JPC_BodyLockWrite,
+    );
+}
+extern "C" {
+    pub fn JPC_NarrowPhaseQuery_CastRay(
+        in_query: *const JPC_NarrowPhaseQuery,
+        in_ray: *const JPC_RRayCast,
+        io_hit: *mut JPC_RayCastResult,
+        in_broad_phase_layer_filter: *const ::std::os::raw::c_void,
+        in_object_layer_filter: *const ::std::os::raw::c_void,
+        in_body_filter: *const ::std::os::raw::c_void,
+    ) -> bool;
+}
+extern "C" {
+    pub fn JPC_PhysicsMaterial_Create(
+        in_debug_name: *const ::std::os::raw::c_char,
+        in_debug_color: u32,
+    ) -> *mut JPC_PhysicsMaterial;
+}
+extern "C" {
+    pub fn JPC_PhysicsMaterial_AddRef(in_material: *const JPC_PhysicsMaterial);
+}
+extern "C" {
+    pub fn JPC_PhysicsMaterial_Release(in_material: *const JPC_PhysicsMaterial);
+}
+extern "C" {
+    pub fn JPC_PhysicsMaterial_GetDebugName(
+        in_material: *const JPC_PhysicsMaterial,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn JPC_PhysicsMaterial_GetDebugColor(in_material: *const JPC_PhysicsMaterial) -> u32;
+}
+extern "C" {
+    pub fn JPC_ShapeSettings_AddRef(in_settings: *mut JPC_ShapeSettings);
+}
+extern "C" {
+    pub fn JPC_ShapeSettings_Release(in_settings: *mut JPC_ShapeSettings);
+}
+extern "C" {
+    pub fn JPC_ShapeSettings_GetRefCount(in_settings: *const JPC_ShapeSettings) -> u32;
+}
+extern "C" {
+    pub fn JPC_ShapeSettings_CreateShape(in_settings: *const JPC_ShapeSettings) -> *mut JPC_Shape;
+}
+extern "C" {
+    pub fn JPC_ShapeSettings_GetError(
+        in_settings: *const JPC_ShapeSettings,
+    ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn JPC_ShapeSettings_GetUserData(in_settings: *const JPC_ShapeSettings) -> u64;
+}
+extern "C" {
+    pub fn JPC_ShapeSettings_SetUserData(in_settings: *mut JPC_ShapeSettings, in_user_data: u64);
+}
+extern "C" {
+    pub fn JPC_ConvexShapeSettings_GetMaterial(
+        in_settings: *const JPC_ConvexShapeSettings,
+    ) -> *const JPC_PhysicsMaterial;
+}
+extern "C" {
+    pub fn JPC_ConvexShapeSettings_SetMaterial(
+        in_settings: *mut JPC_ConvexShapeSettings,
+        in_material: *const JPC_PhysicsMaterial,
+    );
+}
+extern "C" {
+    pub fn JPC_ConvexShapeSettings_GetDensity(in_settings: *const JPC_ConvexShapeSettings) -> f32;
+}
+extern "C" {
+    pub fn JPC_ConvexShapeSettings_SetDensity(
+        in_settings: *mut JPC_ConvexShapeSettings,
+        in_density: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BoxShapeSettings_Create(in_half_extent: *const f32) -> *mut JPC_BoxShapeSettings;
+}
+extern "C" {
+    pub fn JPC_BoxShapeSettings_GetHalfExtent(
+        in_settings: *const JPC_BoxShapeSettings,
+        out_half_extent: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BoxShapeSettings_SetHalfExtent(
+        in_settings: *mut JPC_BoxShapeSettings,
+        in_half_extent: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BoxShapeSettings_GetConvexRadius(in_settings: *const JPC_BoxShapeSettings) -> f32;
+}
+extern "C" {
+    pub fn JPC_BoxShapeSettings_SetConvexRadius(
+        in_settings: *mut JPC_BoxShapeSettings,
+        in_convex_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_SphereShapeSettings_Create(in_radius: f32) -> *mut JPC_SphereShapeSettings;
+}
+extern "C" {
+    pub fn JPC_SphereShapeSettings_GetRadius(in_settings: *const JPC_SphereShapeSettings) -> f32;
+}
+extern "C" {
+    pub fn JPC_SphereShapeSettings_SetRadius(
+        in_settings: *mut JPC_SphereShapeSettings,
+        in_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_TriangleShapeSettings_Create(
+        in_v1: *const f32,
+        in_v2: *const f32,
+        in_v3: *const f32,
+    ) -> *mut JPC_TriangleShapeSettings;
+}
+extern "C" {
+    pub fn JPC_TriangleShapeSettings_SetVertices(
+        in_settings: *mut JPC_TriangleShapeSettings,
+        in_v1: *const f32,
+        in_v2: *const f32,
+        in_v3: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_TriangleShapeSettings_GetVertices(
+        in_settings: *const JPC_TriangleShapeSettings,
+        out_v1: *mut f32,
+        out_v2: *mut f32,
+        out_v3: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_TriangleShapeSettings_GetConvexRadius(
+        in_settings: *const JPC_TriangleShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_TriangleShapeSettings_SetConvexRadius(
+        in_settings: *mut JPC_TriangleShapeSettings,
+        in_convex_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CapsuleShapeSettings_Create(
+        in_half_height_of_cylinder: f32,
+        in_radius: f32,
+    ) -> *mut JPC_CapsuleShapeSettings;
+}
+extern "C" {
+    pub fn JPC_CapsuleShapeSettings_GetHalfHeight(
+        in_settings: *const JPC_CapsuleShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_CapsuleShapeSettings_SetHalfHeight(
+        in_settings: *mut JPC_CapsuleShapeSettings,
+        in_half_height_of_cylinder: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CapsuleShapeSettings_GetRadius(in_settings: *const JPC_CapsuleShapeSettings) -> f32;
+}
+extern "C" {
+    pub fn JPC_CapsuleShapeSettings_SetRadius(
+        in_settings: *mut JPC_CapsuleShapeSettings,
+        in_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_TaperedCapsuleShapeSettings_Create(
+        in_half_height: f32,
+        in_top_radius: f32,
+        in_bottom_radius: f32,
+    ) -> *mut JPC_TaperedCapsuleShapeSettings;
+}
+extern "C" {
+    pub fn JPC_TaperedCapsuleShapeSettings_GetHalfHeight(
+        in_settings: *const JPC_TaperedCapsuleShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_TaperedCapsuleShapeSettings_SetHalfHeight(
+        in_settings: *mut JPC_TaperedCapsuleShapeSettings,
+        in_half_height: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_TaperedCapsuleShapeSettings_GetTopRadius(
+        in_settings: *const JPC_TaperedCapsuleShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_TaperedCapsuleShapeSettings_SetTopRadius(
+        in_settings: *mut JPC_TaperedCapsuleShapeSettings,
+        in_top_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_TaperedCapsuleShapeSettings_GetBottomRadius(
+        in_settings: *const JPC_TaperedCapsuleShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_TaperedCapsuleShapeSettings_SetBottomRadius(
+        in_settings: *mut JPC_TaperedCapsuleShapeSettings,
+        in_bottom_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CylinderShapeSettings_Create(
+        in_half_height: f32,
+        in_radius: f32,
+    ) -> *mut JPC_CylinderShapeSettings;
+}
+extern "C" {
+    pub fn JPC_CylinderShapeSettings_GetConvexRadius(
+        in_settings: *const JPC_CylinderShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_CylinderShapeSettings_SetConvexRadius(
+        in_settings: *mut JPC_CylinderShapeSettings,
+        in_convex_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CylinderShapeSettings_GetHalfHeight(
+        in_settings: *const JPC_CylinderShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_CylinderShapeSettings_SetHalfHeight(
+        in_settings: *mut JPC_CylinderShapeSettings,
+        in_half_height: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CylinderShapeSettings_GetRadius(
+        in_settings: *const JPC_CylinderShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_CylinderShapeSettings_SetRadius(
+        in_settings: *mut JPC_CylinderShapeSettings,
+        in_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_ConvexHullShapeSettings_Create(
+        in_vertices: *const ::std::os::raw::c_void,
+        in_num_vertices: u32,
+        in_vertex_size: u32,
+    ) -> *mut JPC_ConvexHullShapeSettings;
+}
+extern "C" {
+    pub fn JPC_ConvexHullShapeSettings_GetMaxConvexRadius(
+        in_settings: *const JPC_ConvexHullShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_ConvexHullShapeSettings_SetMaxConvexRadius(
+        in_settings: *mut JPC_ConvexHullShapeSettings,
+        in_max_convex_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_ConvexHullShapeSettings_GetMaxErrorConvexRadius(
+        in_settings: *const JPC_ConvexHullShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_ConvexHullShapeSettings_SetMaxErrorConvexRadius(
+        in_settings: *mut JPC_ConvexHullShapeSettings,
+        in_max_err_convex_radius: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_ConvexHullShapeSettings_GetHullTolerance(
+        in_settings: *const JPC_ConvexHullShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_ConvexHullShapeSettings_SetHullTolerance(
+        in_settings: *mut JPC_ConvexHullShapeSettings,
+        in_hull_tolerance: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_Create(
+        in_samples: *const f32,
+        in_height_field_size: u32,
+    ) -> *mut JPC_HeightFieldShapeSettings;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_GetOffset(
+        in_settings: *const JPC_HeightFieldShapeSettings,
+        out_offset: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetOffset(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_offset: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_GetScale(
+        in_settings: *const JPC_HeightFieldShapeSettings,
+        out_scale: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetScale(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_scale: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_GetBlockSize(
+        in_settings: *const JPC_HeightFieldShapeSettings,
+    ) -> u32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetBlockSize(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_block_size: u32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_GetBitsPerSample(
+        in_settings: *const JPC_HeightFieldShapeSettings,
+    ) -> u32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetBitsPerSample(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_num_bits: u32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_GetMinHeightValue(
+        in_settings: *const JPC_HeightFieldShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetMinHeightValue(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_min_height_value: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_GetMaxHeightValue(
+        in_settings: *const JPC_HeightFieldShapeSettings,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetMaxHeightValue(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_max_height_value: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetMaterials(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_materials: *const *const JPC_PhysicsMaterial,
+        in_num_materials: u32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShapeSettings_SetMaterialIndices(
+        in_settings: *mut JPC_HeightFieldShapeSettings,
+        in_material_indices: *const u8,
+        in_num_material_indices: u32,
+    );
+}
+extern "C" {
+    pub fn JPC_MeshShapeSettings_Create(
+        in_vertices: *const ::std::os::raw::c_void,
+        in_num_vertices: u32,
+        in_vertex_size: u32,
+        in_indices: *const u32,
+        in_num_indices: u32,
+    ) -> *mut JPC_MeshShapeSettings;
+}
+extern "C" {
+    pub fn JPC_MeshShapeSettings_GetMaxTrianglesPerLeaf(
+        in_settings: *const JPC_MeshShapeSettings,
+    ) -> u32;
+}
+extern "C" {
+    pub fn JPC_MeshShapeSettings_SetMaxTrianglesPerLeaf(
+        in_settings: *mut JPC_MeshShapeSettings,
+        in_max_triangles: u32,
+    );
+}
+extern "C" {
+    pub fn JPC_MeshShapeSettings_Sanitize(in_settings: *mut JPC_MeshShapeSettings);
+}
+extern "C" {
+    pub fn JPC_RotatedTranslatedShapeSettings_Create(
+        in_inner_shape_settings: *const JPC_ShapeSettings,
+        in_rotated: *const f32,
+        in_translated: *const f32,
+    ) -> *mut JPC_DecoratedShapeSettings;
+}
+extern "C" {
+    pub fn JPC_ScaledShapeSettings_Create(
+        in_inner_shape_settings: *const JPC_ShapeSettings,
+        in_scale: *const f32,
+    ) -> *mut JPC_DecoratedShapeSettings;
+}
+extern "C" {
+    pub fn JPC_OffsetCenterOfMassShapeSettings_Create(
+        in_inner_shape_settings: *const JPC_ShapeSettings,
+        in_center_of_mass: *const f32,
+    ) -> *mut JPC_DecoratedShapeSettings;
+}
+extern "C" {
+    pub fn JPC_OffsetCenterOfMassShapeSettings_GetOffset(
+        in_settings: *const JPC_DecoratedShapeSettings,
+        out_offset: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_OffsetCenterOfMassShapeSettings_SetOffset(
+        in_settings: *mut JPC_DecoratedShapeSettings,
+        in_offset: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_StaticCompoundShapeSettings_Create() -> *mut JPC_CompoundShapeSettings;
+}
+extern "C" {
+    pub fn JPC_MutableCompoundShapeSettings_Create() -> *mut JPC_CompoundShapeSettings;
+}
+extern "C" {
+    pub fn JPC_CompoundShapeSettings_AddShape(
+        in_settings: *mut JPC_CompoundShapeSettings,
+        in_position: *const f32,
+        in_rotation: *const f32,
+        in_shape: *const JPC_ShapeSettings,
+        in_user_data: u32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyManager_DrawSettings_Create() -> *mut JPC_BodyManager_DrawSettings;
+}
+extern "C" {
+    pub fn JPC_BodyManager_DrawSettings_Destroy(arg0: *mut JPC_BodyManager_DrawSettings);
+}
+extern "C" {
+    pub fn JPC_BodyDrawFilter_Create(arg0: JPC_BodyDrawFilterFunc) -> *mut JPC_BodyDrawFilter;
+}
+extern "C" {
+    pub fn JPC_BodyDrawFilter_Destroy(arg0: *mut JPC_BodyDrawFilter);
+}
+extern "C" {
+    pub fn JPC_Shape_AddRef(in_shape: *mut JPC_Shape);
+}
+extern "C" {
+    pub fn JPC_Shape_Release(in_shape: *mut JPC_Shape);
+}
+extern "C" {
+    pub fn JPC_Shape_GetRefCount(in_shape: *const JPC_Shape) -> u32;
+}
+extern "C" {
+    pub fn JPC_Shape_GetType(in_shape: *const JPC_Shape) -> JPC_ShapeType;
+}
+extern "C" {
+    pub fn JPC_Shape_GetSubType(in_shape: *const JPC_Shape) -> JPC_ShapeSubType;
+}
+extern "C" {
+    pub fn JPC_Shape_GetUserData(in_shape: *const JPC_Shape) -> u64;
+}
+extern "C" {
+    pub fn JPC_Shape_SetUserData(in_shape: *mut JPC_Shape, in_user_data: u64);
+}
+extern "C" {
+    pub fn JPC_Shape_GetCenterOfMass(in_shape: *const JPC_Shape, out_position: *mut f32);
+}
+extern "C" {
+    pub fn JPC_Shape_GetMassProperties(
+        in_shape: *const JPC_Shape,
+        out_mass_properties: *mut JPC_MassProperties,
+    );
+}
+extern "C" {
+    pub fn JPC_Shape_GetMaterial(
+        in_shape: *const JPC_Shape,
+        in_sub_shape_id: JPC_SubShapeID,
+    ) -> *const JPC_PhysicsMaterial;
+}
+extern "C" {
+    pub fn JPC_Shape_GetLocalBounds(
+        in_shape: *const JPC_Shape,
+        out_min: *mut f32,
+        out_max: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShape_GetSampleCount(in_shape: *const JPC_Shape) -> u32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShape_GetBlockSize(in_shape: *const JPC_Shape) -> u32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShape_GetMinHeightValue(in_shape: *const JPC_Shape) -> f32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShape_GetMaxHeightValue(in_shape: *const JPC_Shape) -> f32;
+}
+extern "C" {
+    pub fn JPC_HeightFieldShape_GetPosition(
+        in_shape: *const JPC_Shape,
+        in_x: u32,
+        in_y: u32,
+        out_position: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShape_GetHeights(
+        in_shape: *const JPC_Shape,
+        in_x: u32,
+        in_y: u32,
+        in_size_x: u32,
+        in_size_y: u32,
+        out_heights: *mut f32,
+        in_heights_stride: isize,
+    );
+}
+extern "C" {
+    pub fn JPC_HeightFieldShape_SetHeights(
+        in_shape: *mut JPC_Shape,
+        in_x: u32,
+        in_y: u32,
+        in_size_x: u32,
+        in_size_y: u32,
+        in_heights: *const f32,
+        in_heights_stride: isize,
+        in_temp_allocator: *mut JPC_TempAllocator,
+    );
+}
+extern "C" {
+    pub fn JPC_GroupFilter_Create(in_filter: *mut ::std::os::raw::c_void) -> *mut JPC_GroupFilter;
+}
+extern "C" {
+    pub fn JPC_GroupFilter_AddRef(in_filter: *mut JPC_GroupFilter);
+}
+extern "C" {
+    pub fn JPC_GroupFilter_Release(in_filter: *mut JPC_GroupFilter);
+}
+extern "C" {
+    pub fn JPC_GroupFilter_CanCollide(
+        in_filter: *const JPC_GroupFilter,
+        in_group1: *const JPC_CollisionGroup,
+        in_group2: *const JPC_CollisionGroup,
+    ) -> bool;
+}
+extern "C" {
+    pub fn JPC_GroupFilterTable_Create(in_num_sub_groups: u32) -> *mut JPC_GroupFilter;
+}
+extern "C" {
+    pub fn JPC_GroupFilterTable_DisableCollision(
+        in_table: *mut JPC_GroupFilter,
+        in_sub_group1: JPC_CollisionSubGroupID,
+        in_sub_group2: JPC_CollisionSubGroupID,
+    );
+}
+extern "C" {
+    pub fn JPC_GroupFilterTable_EnableCollision(
+        in_table: *mut JPC_GroupFilter,
+        in_sub_group1: JPC_CollisionSubGroupID,
+        in_sub_group2: JPC_CollisionSubGroupID,
+    );
+}
+extern "C" {
+    pub fn JPC_GroupFilterTable_IsCollisionEnabled(
+        in_table: *const JPC_GroupFilter,
+        in_sub_group1: JPC_CollisionSubGroupID,
+        in_sub_group2: JPC_CollisionSubGroupID,
+    ) -> bool;
+}
+extern "C" {
+    pub fn JPC_ConstraintSettings_AddRef(in_settings: *mut JPC_ConstraintSettings);
+}
+extern "C" {
+    pub fn JPC_ConstraintSettings_Release(in_settings: *mut JPC_ConstraintSettings);
+}
+extern "C" {
+    pub fn JPC_ConstraintSettings_GetRefCount(in_settings: *const JPC_ConstraintSettings) -> u32;
+}
+extern "C" {
+    pub fn JPC_ConstraintSettings_GetUserData(in_settings: *const JPC_ConstraintSettings) -> u64;
+}
+extern "C" {
+    pub fn JPC_ConstraintSettings_SetUserData(
+        in_settings: *mut JPC_ConstraintSettings,
+        in_user_data: u64,
+    );
+}
+extern "C" {
+    pub fn JPC_TwoBodyConstraintSettings_CreateConstraint(
+        in_settings: *const JPC_TwoBodyConstraintSettings,
+        in_body1: *mut JPC_Body,
+        in_body2: *mut JPC_Body,
+    ) -> *mut JPC_Constraint;
+}
+extern "C" {
+    pub fn JPC_FixedConstraintSettings_Create() -> *mut JPC_FixedConstraintSettings;
+}
+extern "C" {
+    pub fn JPC_FixedConstraintSettings_SetSpace(
+        in_settings: *mut JPC_FixedConstraintSettings,
+        in_space: JPC_ConstraintSpace,
+    );
+}
+extern "C" {
+    pub fn JPC_FixedConstraintSettings_SetAutoDetectPoint(
+        in_settings: *mut JPC_FixedConstraintSettings,
+        in_enabled: bool,
+    );
+}
+extern "C" {
+    pub fn JPC_Constraint_AddRef(in_shape: *mut JPC_Constraint);
+}
+extern "C" {
+    pub fn JPC_Constraint_Release(in_shape: *mut JPC_Constraint);
+}
+extern "C" {
+    pub fn JPC_Constraint_GetRefCount(in_shape: *const JPC_Constraint) -> u32;
+}
+extern "C" {
+    pub fn JPC_Constraint_GetType(in_shape: *const JPC_Constraint) -> JPC_ConstraintType;
+}
+extern "C" {
+    pub fn JPC_Constraint_GetSubType(in_shape: *const JPC_Constraint) -> JPC_ConstraintSubType;
+}
+extern "C" {
+    pub fn JPC_Constraint_GetUserData(in_shape: *const JPC_Constraint) -> u64;
+}
+extern "C" {
+    pub fn JPC_Constraint_SetUserData(in_shape: *mut JPC_Constraint, in_user_data: u64);
+}
+extern "C" {
+    pub fn JPC_BodyInterface_CreateBody(
+        in_iface: *mut JPC_BodyInterface,
+        in_setting: *const JPC_BodyCreationSettings,
+    ) -> *mut JPC_Body;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_CreateBodyWithID(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_settings: *const JPC_BodyCreationSettings,
+    ) -> *mut JPC_Body;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_DestroyBody(in_iface: *mut JPC_BodyInterface, in_body_id: JPC_BodyID);
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddBody(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_mode: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_RemoveBody(in_iface: *mut JPC_BodyInterface, in_body_id: JPC_BodyID);
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddBodiesPrepare(
+        in_iface: *mut JPC_BodyInterface,
+        io_bodies: *mut JPC_BodyID,
+        in_number: ::std::os::raw::c_int,
+    ) -> *mut JPC_AddBodiesState;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddBodiesFinalize(
+        in_iface: *mut JPC_BodyInterface,
+        io_bodies: *mut JPC_BodyID,
+        in_number: ::std::os::raw::c_int,
+        in_add_state: *mut JPC_AddBodiesState,
+        in_mode: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddBodiesAbort(
+        in_iface: *mut JPC_BodyInterface,
+        io_bodies: *mut JPC_BodyID,
+        in_number: ::std::os::raw::c_int,
+        in_add_state: *mut JPC_AddBodiesState,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_RemoveBodies(
+        in_iface: *mut JPC_BodyInterface,
+        io_bodies: *mut JPC_BodyID,
+        in_number: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_DestroyBodies(
+        in_iface: *mut JPC_BodyInterface,
+        in_bodies: *const JPC_BodyID,
+        in_number: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_ActivateBodies(
+        in_iface: *mut JPC_BodyInterface,
+        in_bodies: *const JPC_BodyID,
+        in_number: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_DeactivateBodies(
+        in_iface: *mut JPC_BodyInterface,
+        in_bodies: *const JPC_BodyID,
+        in_number: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_CreateAndAddBody(
+        in_iface: *mut JPC_BodyInterface,
+        in_settings: *const JPC_BodyCreationSettings,
+        in_mode: JPC_Activation,
+    ) -> JPC_BodyID;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_IsAdded(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> bool;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetLinearAndAngularVelocity(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_linear_velocity: *const f32,
+        in_angular_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetLinearAndAngularVelocity(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_linear_velocity: *mut f32,
+        out_angular_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetLinearVelocity(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetLinearVelocity(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddLinearVelocity(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddLinearAndAngularVelocity(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_linear_velocity: *const f32,
+        in_angular_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetAngularVelocity(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetAngularVelocity(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetPointVelocity(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_point: *const JPC_Real,
+        out_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetPosition(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_position: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetPosition(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_position: *const JPC_Real,
+        in_activation: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetCenterOfMassPosition(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_position: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetRotation(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_rotation: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetRotation(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_rotation: *const f32,
+        in_activation: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_ActivateBody(in_iface: *mut JPC_BodyInterface, in_body_id: JPC_BodyID);
+}
+extern "C" {
+    pub fn JPC_BodyInterface_DeactivateBody(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_IsActive(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> bool;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetPositionRotationAndVelocity(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_position: *const JPC_Real,
+        in_rotation: *const f32,
+        in_linear_velocity: *const f32,
+        in_angular_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddForce(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_force: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddForceAtPosition(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_force: *const f32,
+        in_position: *const JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddTorque(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_torque: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddForceAndTorque(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_force: *const f32,
+        in_torque: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddImpulse(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_impulse: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddImpulseAtPosition(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_impulse: *const f32,
+        in_position: *const JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_AddAngularImpulse(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_impulse: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetMotionType(
+        in_iface: *const JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> JPC_MotionType;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetMotionType(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        motion_type: JPC_MotionType,
+        activation: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetObjectLayer(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> JPC_ObjectLayer;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetObjectLayer(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_layer: JPC_ObjectLayer,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetShape(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> *const JPC_Shape;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetShape(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_shape: *const JPC_Shape,
+        in_update_mass_properties: bool,
+        in_activation: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_NotifyShapeChanged(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_previous_center_of_mass: *const f32,
+        in_update_mass_properties: bool,
+        in_activation: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetPositionAndRotation(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_position: *const JPC_Real,
+        in_rotation: *const f32,
+        in_activation: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetPositionAndRotationWhenChanged(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_position: *const JPC_Real,
+        in_rotation: *const f32,
+        in_activation: JPC_Activation,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetWorldTransform(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_rotation: *mut f32,
+        out_translation: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetCenterOfMassTransform(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_rotation: *mut f32,
+        out_translation: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_MoveKinematic(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_target_position: *const JPC_Real,
+        in_target_rotation: *const f32,
+        in_delta_time: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetFriction(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetFriction(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_friction: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetRestitution(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetRestitution(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_restitution: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetGravityFactor(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> f32;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetGravityFactor(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_gravity_factor: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetUserData(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+    ) -> u64;
+}
+extern "C" {
+    pub fn JPC_BodyInterface_SetUserData(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        in_user_data: u64,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetInverseInertia(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_inverse_inertia: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_ActivateBodiesInAABox(
+        in_iface: *mut JPC_BodyInterface,
+        in_min: *const f32,
+        in_max: *const f32,
+        in_broad_phase_layer_filter: *const ::std::os::raw::c_void,
+        in_object_layer_filter: *const ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyInterface_GetTransformedShape(
+        in_iface: *mut JPC_BodyInterface,
+        in_body_id: JPC_BodyID,
+        out_shape: *mut JPC_TransformedShape,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetID(in_body: *const JPC_Body) -> JPC_BodyID;
+}
+extern "C" {
+    pub fn JPC_Body_IsActive(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_IsStatic(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_IsKinematic(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_IsDynamic(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_CanBeKinematicOrDynamic(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_SetIsSensor(in_body: *mut JPC_Body, in_is_sensor: bool);
+}
+extern "C" {
+    pub fn JPC_Body_IsSensor(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_GetMotionType(in_body: *const JPC_Body) -> JPC_MotionType;
+}
+extern "C" {
+    pub fn JPC_Body_SetMotionType(in_body: *mut JPC_Body, in_motion_type: JPC_MotionType);
+}
+extern "C" {
+    pub fn JPC_Body_GetBroadPhaseLayer(in_body: *const JPC_Body) -> JPC_BroadPhaseLayer;
+}
+extern "C" {
+    pub fn JPC_Body_GetObjectLayer(in_body: *const JPC_Body) -> JPC_ObjectLayer;
+}
+extern "C" {
+    pub fn JPC_Body_GetCollisionGroup(in_body: *mut JPC_Body) -> *mut JPC_CollisionGroup;
+}
+extern "C" {
+    pub fn JPC_Body_SetCollisionGroup(in_body: *mut JPC_Body, in_group: *const JPC_CollisionGroup);
+}
+extern "C" {
+    pub fn JPC_Body_GetAllowSleeping(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_SetAllowSleeping(in_body: *mut JPC_Body, in_allow_sleeping: bool);
+}
+extern "C" {
+    pub fn JPC_Body_GetFriction(in_body: *const JPC_Body) -> f32;
+}
+extern "C" {
+    pub fn JPC_Body_SetFriction(in_body: *mut JPC_Body, in_friction: f32);
+}
+extern "C" {
+    pub fn JPC_Body_GetRestitution(in_body: *const JPC_Body) -> f32;
+}
+extern "C" {
+    pub fn JPC_Body_SetRestitution(in_body: *mut JPC_Body, in_restitution: f32);
+}
+extern "C" {
+    pub fn JPC_Body_GetLinearVelocity(in_body: *const JPC_Body, out_linear_velocity: *mut f32);
+}
+extern "C" {
+    pub fn JPC_Body_SetLinearVelocity(in_body: *mut JPC_Body, in_linear_velocity: *const f32);
+}
+extern "C" {
+    pub fn JPC_Body_SetLinearVelocityClamped(
+        in_body: *mut JPC_Body,
+        in_linear_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetAngularVelocity(in_body: *const JPC_Body, out_angular_velocity: *mut f32);
+}
+extern "C" {
+    pub fn JPC_Body_SetAnglularVelocity(in_body: *mut JPC_Body, in_angular_velocity: *const f32);
+}
+extern "C" {
+    pub fn JPC_Body_SetAnglularVelocityClamped(
+        in_body: *mut JPC_Body,
+        in_angular_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetPointVelocityCOM(
+        in_body: *const JPC_Body,
+        in_point_relative_to_com: *const f32,
+        out_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetPointVelocity(
+        in_body: *const JPC_Body,
+        in_point: *const JPC_Real,
+        out_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_AddForce(in_body: *mut JPC_Body, in_force: *const f32);
+}
+extern "C" {
+    pub fn JPC_Body_AddForceAtPosition(
+        in_body: *mut JPC_Body,
+        in_force: *const f32,
+        in_position: *const JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_AddTorque(in_body: *mut JPC_Body, in_torque: *const f32);
+}
+extern "C" {
+    pub fn JPC_Body_GetInverseInertia(in_body: *const JPC_Body, out_inverse_inertia: *mut f32);
+}
+extern "C" {
+    pub fn JPC_Body_AddImpulse(in_body: *mut JPC_Body, in_impulse: *const f32);
+}
+extern "C" {
+    pub fn JPC_Body_AddImpulseAtPosition(
+        in_body: *mut JPC_Body,
+        in_impulse: *const f32,
+        in_position: *const JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_AddAngularImpulse(in_body: *mut JPC_Body, in_angular_impulse: *const f32);
+}
+extern "C" {
+    pub fn JPC_Body_MoveKinematic(
+        in_body: *mut JPC_Body,
+        in_target_position: *const JPC_Real,
+        in_target_rotation: *const f32,
+        in_delta_time: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_ApplyBuoyancyImpulse(
+        in_body: *mut JPC_Body,
+        in_surface_position: *const JPC_Real,
+        in_surface_normal: *const f32,
+        in_buoyancy: f32,
+        in_linear_drag: f32,
+        in_angular_drag: f32,
+        in_fluid_velocity: *const f32,
+        in_gravity: *const f32,
+        in_delta_time: f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_IsInBroadPhase(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_IsCollisionCacheInvalid(in_body: *const JPC_Body) -> bool;
+}
+extern "C" {
+    pub fn JPC_Body_GetShape(in_body: *const JPC_Body) -> *const JPC_Shape;
+}
+extern "C" {
+    pub fn JPC_Body_GetPosition(in_body: *const JPC_Body, out_position: *mut JPC_Real);
+}
+extern "C" {
+    pub fn JPC_Body_GetRotation(in_body: *const JPC_Body, out_rotation: *mut f32);
+}
+extern "C" {
+    pub fn JPC_Body_GetWorldTransform(
+        in_body: *const JPC_Body,
+        out_rotation: *mut f32,
+        out_translation: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetCenterOfMassPosition(in_body: *const JPC_Body, out_position: *mut JPC_Real);
+}
+extern "C" {
+    pub fn JPC_Body_GetCenterOfMassTransform(
+        in_body: *const JPC_Body,
+        out_rotation: *mut f32,
+        out_translation: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetInverseCenterOfMassTransform(
+        in_body: *const JPC_Body,
+        out_rotation: *mut f32,
+        out_translation: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetWorldSpaceBounds(
+        in_body: *const JPC_Body,
+        out_min: *mut f32,
+        out_max: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Body_GetMotionProperties(in_body: *mut JPC_Body) -> *mut JPC_MotionProperties;
+}
+extern "C" {
+    pub fn JPC_Body_GetUserData(in_body: *const JPC_Body) -> u64;
+}
+extern "C" {
+    pub fn JPC_Body_SetUserData(in_body: *mut JPC_Body, in_user_data: u64);
+}
+extern "C" {
+    pub fn JPC_Body_GetWorldSpaceSurfaceNormal(
+        in_body: *const JPC_Body,
+        in_sub_shape_id: JPC_SubShapeID,
+        in_position: *const JPC_Real,
+        out_normal_vector: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_BodyID_GetIndex(in_body_id: JPC_BodyID) -> u32;
+}
+extern "C" {
+    pub fn JPC_BodyID_GetSequenceNumber(in_body_id: JPC_BodyID) -> u8;
+}
+extern "C" {
+    pub fn JPC_BodyID_IsInvalid(in_body_id: JPC_BodyID) -> bool;
+}
+extern "C" {
+    pub fn JPC_CharacterSettings_Create() -> *mut JPC_CharacterSettings;
+}
+extern "C" {
+    pub fn JPC_CharacterSettings_Release(in_settings: *mut JPC_CharacterSettings);
+}
+extern "C" {
+    pub fn JPC_CharacterSettings_AddRef(in_settings: *mut JPC_CharacterSettings);
+}
+extern "C" {
+    pub fn JPC_Character_Create(
+        in_settings: *const JPC_CharacterSettings,
+        in_position: *const JPC_Real,
+        in_rotation: *const f32,
+        in_user_data: u64,
+        in_physics_system: *mut JPC_PhysicsSystem,
+    ) -> *mut JPC_Character;
+}
+extern "C" {
+    pub fn JPC_Character_Destroy(in_character: *mut JPC_Character);
+}
+extern "C" {
+    pub fn JPC_Character_AddToPhysicsSystem(
+        in_character: *mut JPC_Character,
+        in_activation: JPC_Activation,
+        in_lock_bodies: bool,
+    );
+}
+extern "C" {
+    pub fn JPC_Character_RemoveFromPhysicsSystem(
+        in_character: *mut JPC_Character,
+        in_lock_bodies: bool,
+    );
+}
+extern "C" {
+    pub fn JPC_Character_GetPosition(
+        in_character: *const JPC_Character,
+        out_position: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_Character_SetPosition(
+        in_character: *mut JPC_Character,
+        in_position: *const JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_Character_GetLinearVelocity(
+        in_character: *const JPC_Character,
+        out_linear_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_Character_SetLinearVelocity(
+        in_character: *mut JPC_Character,
+        in_linear_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtualSettings_Create() -> *mut JPC_CharacterVirtualSettings;
+}
+extern "C" {
+    pub fn JPC_CharacterVirtualSettings_Release(in_settings: *mut JPC_CharacterVirtualSettings);
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_Create(
+        in_settings: *const JPC_CharacterVirtualSettings,
+        in_position: *const JPC_Real,
+        in_rotation: *const f32,
+        in_physics_system: *mut JPC_PhysicsSystem,
+    ) -> *mut JPC_CharacterVirtual;
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_Destroy(in_character: *mut JPC_CharacterVirtual);
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_Update(
+        in_character: *mut JPC_CharacterVirtual,
+        in_delta_time: f32,
+        in_gravity: *const f32,
+        in_broad_phase_layer_filter: *const ::std::os::raw::c_void,
+        in_object_layer_filter: *const ::std::os::raw::c_void,
+        in_body_filter: *const ::std::os::raw::c_void,
+        in_shape_filter: *const ::std::os::raw::c_void,
+        in_temp_allocator: *mut JPC_TempAllocator,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_SetListener(
+        in_character: *mut JPC_CharacterVirtual,
+        in_listener: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_UpdateGroundVelocity(in_character: *mut JPC_CharacterVirtual);
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_GetGroundVelocity(
+        in_character: *const JPC_CharacterVirtual,
+        out_ground_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_GetGroundState(
+        in_character: *mut JPC_CharacterVirtual,
+    ) -> JPC_CharacterGroundState;
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_GetPosition(
+        in_character: *const JPC_CharacterVirtual,
+        out_position: *mut JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_SetPosition(
+        in_character: *mut JPC_CharacterVirtual,
+        in_position: *const JPC_Real,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_GetRotation(
+        in_character: *const JPC_CharacterVirtual,
+        out_rotation: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_SetRotation(
+        in_character: *mut JPC_CharacterVirtual,
+        in_rotation: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_GetLinearVelocity(
+        in_character: *const JPC_CharacterVirtual,
+        out_linear_velocity: *mut f32,
+    );
+}
+extern "C" {
+    pub fn JPC_CharacterVirtual_SetLinearVelocity(
+        in_character: *mut JPC_CharacterVirtual,
+        in_linear_velocity: *const f32,
+    );
+}
+extern "C" {
+    pub fn JPC_GetFeatures() -> JPC_Features;
+}
+pub const JPC_BODY_ID_INDEX_BITS: u32 = 0x007fffff;
+pub const JPC_PI: f64 = 3.14159265358979323846;
+pub const JPC_DEBUG_RENDERER: u32 = 1;
+pub const JPC_BODY_ID_SEQUENCE_SHIFT: u32 = 24;
+pub const JPC_COLLISION_GROUP_INVALID_GROUP: u32 = 0xffffffff;
+pub const JPC_COLLISION_GROUP_INVALID_SUB_GROUP: u32 = 0xffffffff;
+pub const JPC_SUB_SHAPE_ID_EMPTY: u32 = 0xffffffff;
+pub const JPC_BODY_ID_INVALID: u32 = 0xffffffff;
+pub const JPC_BODY_ID_SEQUENCE_BITS: u32 = 0xff000000;
+pub const JPC_DOUBLE_PRECISION: u32 = 1;
+pub const JPC_ENABLE_ASSERTS: u32 = 0;
@@ -2,4 +2,39 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+// bindgen copies JPC_PI from the header verbatim, which clippy flags
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
+mod bindings {
+    #[cfg(feature = "bindgen")]
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+    #[cfg(all(
+        not(feature = "bindgen"),
+        target_env = "msvc",
+        not(feature = "double-precision")
+    ))]
+    include!("bindings/msvc/single_precision.rs");
+
+    #[cfg(all(
+        not(feature = "bindgen"),
+        target_env = "msvc",
+        feature = "double-precision"
+    ))]
+    include!("bindings/msvc/double_precision.rs");
+
+    #[cfg(all(
+        not(feature = "bindgen"),
+        not(target_env = "msvc"),
+        not(feature = "double-precision")
+    ))]
+    include!("bindings/itanium/single_precision.rs");
+
+    #[cfg(all(
+        not(feature = "bindgen"),
+        not(target_env = "msvc"),
+        feature = "double-precision"
+    ))]
+    include!("bindings/itanium/double_precision.rs");
+}
+
+pub use bindings::*;
@@ -1,7 +1,143 @@
-use jolt_sys::{JPC_AssertFailedFunction, JPC_SetAssertFailedHandler};
+use crate::abort_on_panic;
+use jolt_sys::{JPC_AssertFailedFunction, JPC_SetAssertFailedHandler, JPC_SetTraceHandler};
+use std::borrow::Cow;
+use std::ffi::{c_char, c_uint, CStr};
+use std::sync::{Arc, RwLock};
+
+type AssertHandler = Arc<dyn Fn(&AssertInfo) -> AssertPolicy + Send + Sync>;
+type TraceHandler = Arc<dyn Fn(&str) + Send + Sync>;
+
+static ASSERT_HANDLER: RwLock<Option<AssertHandler>> = RwLock::new(None);
+static TRACE_HANDLER: RwLock<Option<TraceHandler>> = RwLock::new(None);
+
+/// A failed assertion inside Jolt. Only reported when Jolt is built with asserts enabled.
+#[derive(Debug, Clone)]
+pub struct AssertInfo<'a> {
+    pub expression: Cow<'a, str>,
+    pub message: Option<Cow<'a, str>>,
+    pub file: Cow<'a, str>,
+    pub line: u32,
+}
+
+impl std::fmt::Display for AssertInfo<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: ({})", self.file, self.line, self.expression)?;
+        if let Some(message) = &self.message {
+            write!(f, " {message}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// What to do after an assert handler has run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssertPolicy {
+    /// Trigger a debugger breakpoint.
+    Break,
+    /// Run the panic hook with the assert message, then abort the process. Unwinding into Jolt
+    /// isn't possible, so there is no way to recover from this.
+    Abort,
+    /// Let Jolt continue. With the `log` feature the assert is also logged as an error, under the
+    /// `jolt` target; otherwise reporting it is up to the handler.
+    Log,
+}
 
 pub fn set_assert_failed_handler(handler: JPC_AssertFailedFunction) {
     unsafe {
         JPC_SetAssertFailedHandler(handler);
     }
 }
+
+/// Calls `handler` for every failed Jolt assert, the returned policy decides what happens next.
+///
+/// The handler may replace itself by calling this again.
+pub fn set_assert_handler<F>(handler: F)
+where
+    F: Fn(&AssertInfo) -> AssertPolicy + Send + Sync + 'static,
+{
+    *ASSERT_HANDLER.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(handler));
+    set_assert_failed_handler(Some(assert_failed_trampoline));
+}
+
+/// Applies the same policy to every failed Jolt assert.
+pub fn set_assert_policy(policy: AssertPolicy) {
+    set_assert_handler(move |_| policy);
+}
+
+/// Calls `handler` with every message Jolt traces.
+pub fn set_trace_handler<F>(handler: F)
+where
+    F: Fn(&str) + Send + Sync + 'static,
+{
+    *TRACE_HANDLER.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(handler));
+    unsafe {
+        JPC_SetTraceHandler(Some(trace_trampoline));
+    }
+}
+
+/// Forwards Jolt's trace output to the `log` crate, under the `jolt` target.
+#[cfg(feature = "log")]
+pub fn forward_traces_to_log() {
+    set_trace_handler(|message| log::info!(target: "jolt", "{}", message.trim_end()));
+}
+
+unsafe fn cstr_lossy<'a>(ptr: *const c_char) -> Option<Cow<'a, str>> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy())
+    }
+}
+
+unsafe extern "C" fn assert_failed_trampoline(
+    expression: *const c_char,
+    message: *const c_char,
+    file: *const c_char,
+    line: c_uint,
+) -> bool {
+    let info = AssertInfo {
+        expression: cstr_lossy(expression).unwrap_or_default(),
+        message: cstr_lossy(message),
+        file: cstr_lossy(file).unwrap_or_default(),
+        line,
+    };
+
+    // Cloned out so the handler can replace itself without deadlocking.
+    let handler = ASSERT_HANDLER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    let policy = match handler {
+        Some(handler) => abort_on_panic(|| handler(&info)),
+        None => AssertPolicy::Break,
+    };
+
+    match policy {
+        AssertPolicy::Break => true,
+        AssertPolicy::Log => {
+            #[cfg(feature = "log")]
+            log::error!(target: "jolt", "Assert failed: {info}");
+            false
+        }
+        AssertPolicy::Abort => {
+            let message = format!("Jolt assert failed: {info}");
+            let _ = std::panic::catch_unwind(move || panic!("{message}"));
+            std::process::abort();
+        }
+    }
+}
+
+unsafe extern "C" fn trace_trampoline(message: *const c_char) {
+    let Some(message) = cstr_lossy(message) else {
+        return;
+    };
+
+    let handler = TRACE_HANDLER
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    if let Some(handler) = handler {
+        abort_on_panic(|| handler(&message));
+    }
+}
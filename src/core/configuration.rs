@@ -1,7 +1,7 @@
 use bitflags::bitflags;
 
 bitflags! {
-    #[derive(Debug)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct ConfigurationFeatures: u32 {
         const DOUBLE_PRECISION              = jolt_sys::JPC_EFeatures_JPC_FEATURE_DOUBLE_PRECISION as u32;
         const NEON                          = jolt_sys::JPC_EFeatures_JPC_FEATURE_NEON as u32;
@@ -18,9 +18,81 @@ bitflags! {
         const PLATFORM_DETERMINISTIC        = jolt_sys::JPC_EFeatures_JPC_FEATURE_PLATFORM_DETERMINISTIC as u32;
         const FLOATING_POINT_EXCEPTIONS     = jolt_sys::JPC_EFeatures_JPC_FEATURE_FLOATING_POINT_EXCEPTIONS as u32;
         const DEBUG                         = jolt_sys::JPC_EFeatures_JPC_FEATURE_DEBUG as u32;
+        const DEBUG_RENDERER                = jolt_sys::JPC_EFeatures_JPC_FEATURE_DEBUG_RENDERER as u32;
+        const PROFILER                      = jolt_sys::JPC_EFeatures_JPC_FEATURE_PROFILER as u32;
+        const ASSERTS                       = jolt_sys::JPC_EFeatures_JPC_FEATURE_ASSERTS as u32;
     }
 }
 
 pub fn get_features() -> ConfigurationFeatures {
     ConfigurationFeatures::from_bits_retain(unsafe { jolt_sys::JPC_GetFeatures() })
 }
+
+/// The features this crate was compiled to expect from Jolt, based on its cargo features.
+pub fn expected_features() -> ConfigurationFeatures {
+    let mut features = ConfigurationFeatures::empty();
+    features.set(
+        ConfigurationFeatures::DOUBLE_PRECISION,
+        cfg!(feature = "double-precision"),
+    );
+    features.set(
+        ConfigurationFeatures::PLATFORM_DETERMINISTIC,
+        cfg!(feature = "cross-platform-deterministic"),
+    );
+    features.set(
+        ConfigurationFeatures::DEBUG_RENDERER,
+        cfg!(feature = "debug-renderer"),
+    );
+    features.set(ConfigurationFeatures::PROFILER, cfg!(feature = "profiler"));
+    features.set(ConfigurationFeatures::ASSERTS, cfg!(feature = "asserts"));
+    // Jolt only supports floating point exceptions when compiled with MSVC
+    features.set(
+        ConfigurationFeatures::FLOATING_POINT_EXCEPTIONS,
+        cfg!(all(
+            feature = "floating-point-exceptions",
+            target_env = "msvc"
+        )),
+    );
+    features
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureMismatch {
+    pub expected: ConfigurationFeatures,
+    pub actual: ConfigurationFeatures,
+}
+
+impl std::fmt::Display for FeatureMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Jolt was built with a different configuration than the bindings expect (expected {:?}, got {:?})",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for FeatureMismatch {}
+
+/// Checks that the linked Jolt library was built with the same configuration as this crate.
+///
+/// Asserts change the layout of `JPC_MotionProperties`, so a Jolt built in its Debug
+/// configuration (which always enables them) only matches with the `asserts` feature.
+pub fn verify_features() -> Result<(), FeatureMismatch> {
+    let mut checked = ConfigurationFeatures::DOUBLE_PRECISION
+        | ConfigurationFeatures::PLATFORM_DETERMINISTIC
+        | ConfigurationFeatures::DEBUG_RENDERER
+        | ConfigurationFeatures::PROFILER
+        | ConfigurationFeatures::ASSERTS;
+    if cfg!(target_env = "msvc") {
+        checked |= ConfigurationFeatures::FLOATING_POINT_EXCEPTIONS;
+    }
+
+    let expected = expected_features();
+    let actual = get_features();
+    if (expected & checked) != (actual & checked) {
+        return Err(FeatureMismatch { expected, actual });
+    }
+
+    Ok(())
+}
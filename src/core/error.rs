@@ -0,0 +1,62 @@
+use crate::{BodyId, PhysicsUpdateError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Jolt couldn't create a shape from its settings, contains Jolt's error message.
+    ShapeCreation(String),
+    /// The samples or materials passed to a height field don't fit its grid.
+    InvalidHeightField(String),
+    /// The physics system already holds its maximum number of bodies.
+    TooManyBodies,
+    /// The body ID doesn't refer to a body, or is already in use when creating a body with it.
+    InvalidBodyId(BodyId),
+    /// One or more of the physics system's contact buffers overflowed during an update.
+    PhysicsUpdate(PhysicsUpdateError),
+    /// Jolt returned a value that doesn't map to any variant of the named enum.
+    UnknownEnumValue { name: &'static str, value: u32 },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ShapeCreation(message) => write!(f, "Failed to create shape: {message}"),
+            Error::InvalidHeightField(message) => write!(f, "Invalid height field: {message}"),
+            Error::TooManyBodies => write!(f, "Maximum number of bodies reached"),
+            Error::InvalidBodyId(body_id) => write!(f, "Invalid body ID {body_id:#x}"),
+            Error::PhysicsUpdate(errors) => write!(f, "Physics update hit its limits: {errors:?}"),
+            Error::UnknownEnumValue { name, value } => write!(f, "Unknown {name} value {value}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<PhysicsUpdateError> for Error {
+    fn from(value: PhysicsUpdateError) -> Self {
+        Error::PhysicsUpdate(value)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Implements `TryFrom<$raw>` for a fieldless enum whose discriminants mirror a jolt-sys enum.
+macro_rules! impl_enum_try_from {
+    ($ty:ident: $raw:ty { $($variant:ident),* $(,)? }) => {
+        impl TryFrom<$raw> for $ty {
+            type Error = $crate::Error;
+
+            fn try_from(value: $raw) -> $crate::Result<Self> {
+                $(
+                    if value == $ty::$variant as $raw {
+                        return Ok($ty::$variant);
+                    }
+                )*
+                Err($crate::Error::UnknownEnumValue {
+                    name: stringify!($ty),
+                    value: value as u32,
+                })
+            }
+        }
+    };
+}
+pub(crate) use impl_enum_try_from;
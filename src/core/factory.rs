@@ -1,10 +1,10 @@
-pub fn create_factory() {
+pub(crate) fn create_factory() {
     unsafe {
         jolt_sys::JPC_CreateFactory();
     }
 }
 
-pub fn destroy_factory() {
+pub(crate) fn destroy_factory() {
     unsafe {
         jolt_sys::JPC_DestroyFactory();
     }
@@ -1,29 +1,192 @@
-pub struct JobSystem(*mut jolt_sys::JPC_JobSystem);
+use crate::{abort_on_panic, JoltRuntime};
+use std::ffi::c_void;
+use std::ptr::NonNull;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+pub struct JobSystem {
+    inner: *mut jolt_sys::JPC_JobSystem,
+    // Referenced by the C++ job system through its user data, so it has to outlive it.
+    _executor: Option<Box<Arc<dyn JobExecutor>>>,
+    _runtime: JoltRuntime,
+}
 
 impl JobSystem {
     pub const MAX_PHYSICS_JOBS: u32 = jolt_sys::JPC_JobSystemConstants_JPC_MAX_PHYSICS_JOBS as _;
     pub const MAX_PHYSICS_BARRIERS: u32 =
         jolt_sys::JPC_JobSystemConstants_JPC_MAX_PHYSICS_BARRIERS as _;
 
-    pub fn create(max_jobs: u32, max_barriers: u32, num_threads: i32) -> Self {
+    pub fn create(
+        runtime: &JoltRuntime,
+        max_jobs: u32,
+        max_barriers: u32,
+        num_threads: i32,
+    ) -> Self {
         unsafe {
-            JobSystem(jolt_sys::JPC_JobSystem_Create(
-                max_jobs,
-                max_barriers,
-                num_threads,
-            ))
+            JobSystem {
+                inner: jolt_sys::JPC_JobSystem_Create(max_jobs, max_barriers, num_threads),
+                _executor: None,
+                _runtime: runtime.clone(),
+            }
+        }
+    }
+
+    /// Creates a job system without worker threads, all physics work runs inline on the thread
+    /// calling `PhysicsSystem::update`.
+    pub fn single_threaded(runtime: &JoltRuntime, max_jobs: u32) -> Self {
+        unsafe {
+            JobSystem {
+                inner: jolt_sys::JPC_JobSystem_CreateSingleThreaded(max_jobs),
+                _executor: None,
+                _runtime: runtime.clone(),
+            }
+        }
+    }
+
+    /// Creates a job system that runs Jolt's jobs on `executor` instead of Jolt's own thread pool.
+    pub fn with_executor(
+        runtime: &JoltRuntime,
+        max_barriers: u32,
+        executor: Arc<dyn JobExecutor>,
+    ) -> Self {
+        let max_concurrency = executor.max_concurrency().max(1) as u32;
+        let executor = Box::new(executor);
+        unsafe {
+            JobSystem {
+                inner: jolt_sys::JPC_JobSystem_CreateCustom(
+                    max_barriers,
+                    max_concurrency,
+                    Some(queue_job_trampoline),
+                    &*executor as *const Arc<dyn JobExecutor> as *mut c_void,
+                ),
+                _executor: Some(executor),
+                _runtime: runtime.clone(),
+            }
         }
     }
 
     pub(crate) fn as_ptr(&self) -> *mut jolt_sys::JPC_JobSystem {
-        self.0
+        self.inner
     }
 }
 
 impl Drop for JobSystem {
     fn drop(&mut self) {
         unsafe {
-            jolt_sys::JPC_JobSystem_Destroy(self.0);
+            if self._executor.is_some() {
+                // Jobs the executor still holds keep the C++ side alive until they are dropped,
+                // but they are never queued again, so the executor itself can go.
+                jolt_sys::JPC_JobSystem_DestroyCustom(self.inner);
+            } else {
+                jolt_sys::JPC_JobSystem_Destroy(self.inner);
+            }
+        }
+    }
+}
+
+unsafe impl Send for JobSystem {}
+
+/// Runs the jobs of a [`JobSystem`] created with [`JobSystem::with_executor`].
+pub trait JobExecutor: Send + Sync + 'static {
+    /// The number of jobs that can run in parallel, including the thread calling
+    /// `PhysicsSystem::update`, which also runs jobs while it waits.
+    fn max_concurrency(&self) -> usize;
+
+    /// Schedules `job` to be run. Jobs may be run on any thread and in any order, but every job
+    /// must eventually be run, or the physics update waiting for it never finishes.
+    ///
+    /// The thread waiting in `PhysicsSystem::update` may run a job before the executor does, in
+    /// which case running it again is a no-op. Jobs can be held past the update, and even past
+    /// dropping the [`JobSystem`].
+    fn execute(&self, job: Job);
+}
+
+/// A physics job that is ready to run.
+pub struct Job(NonNull<jolt_sys::JPC_Job>);
+
+unsafe impl Send for Job {}
+
+impl Job {
+    pub fn run(self) {
+        unsafe {
+            jolt_sys::JPC_Job_Execute(self.0.as_ptr());
+        }
+    }
+}
+
+impl Drop for Job {
+    fn drop(&mut self) {
+        unsafe {
+            jolt_sys::JPC_Job_Release(self.0.as_ptr());
+        }
+    }
+}
+
+unsafe extern "C" fn queue_job_trampoline(user_data: *mut c_void, job: *mut jolt_sys::JPC_Job) {
+    let executor = &*(user_data as *const Arc<dyn JobExecutor>);
+    abort_on_panic(|| executor.execute(Job(NonNull::new_unchecked(job))));
+}
+
+/// A [`JobExecutor`] backed by a fixed number of `std::thread` workers.
+pub struct ThreadPoolExecutor {
+    sender: Option<Mutex<Sender<Job>>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPoolExecutor {
+    pub fn new(num_threads: usize) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..num_threads)
+            .map(|i| {
+                let receiver = receiver.clone();
+                std::thread::Builder::new()
+                    .name(format!("jolt-worker-{i}"))
+                    .spawn(move || Self::worker(&receiver))
+                    .expect("Failed to spawn Jolt worker thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(Mutex::new(sender)),
+            workers,
+        }
+    }
+
+    fn worker(receiver: &Mutex<Receiver<Job>>) {
+        loop {
+            let job = receiver.lock().unwrap_or_else(|e| e.into_inner()).recv();
+            match job {
+                Ok(job) => job.run(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl JobExecutor for ThreadPoolExecutor {
+    fn max_concurrency(&self) -> usize {
+        self.workers.len() + 1
+    }
+
+    fn execute(&self, job: Job) {
+        let sender = self.sender.as_ref().unwrap();
+        if let Err(mpsc::SendError(job)) =
+            sender.lock().unwrap_or_else(|e| e.into_inner()).send(job)
+        {
+            job.run();
+        }
+    }
+}
+
+impl Drop for ThreadPoolExecutor {
+    fn drop(&mut self) {
+        // Closing the channel stops the workers once the queue is drained
+        self.sender.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
         }
     }
 }
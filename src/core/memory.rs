@@ -1,17 +1,242 @@
-pub fn register_default_allocator() {
+use crate::JoltRuntime;
+use std::alloc::Layout;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Which allocator Jolt uses for its internal allocations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Allocator {
+    /// Jolt's built-in malloc/free based allocator.
+    #[default]
+    Default,
+    /// Route every allocation through Rust's `#[global_allocator]`.
+    ///
+    /// With `track_statistics` enabled, [`allocator_stats`] reports live and peak usage.
+    Rust { track_statistics: bool },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocatorStats {
+    pub live_bytes: usize,
+    pub peak_bytes: usize,
+    pub live_allocations: usize,
+    pub total_allocations: usize,
+}
+
+static TRACK_STATISTICS: AtomicBool = AtomicBool::new(false);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static TOTAL_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the statistics of the Rust allocator, or `None` if Jolt isn't using
+/// [`Allocator::Rust`] with `track_statistics` enabled.
+pub fn allocator_stats() -> Option<AllocatorStats> {
+    if !TRACK_STATISTICS.load(Ordering::Relaxed) {
+        return None;
+    }
+
+    Some(AllocatorStats {
+        live_bytes: LIVE_BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed),
+        live_allocations: LIVE_ALLOCATIONS.load(Ordering::Relaxed),
+        total_allocations: TOTAL_ALLOCATIONS.load(Ordering::Relaxed),
+    })
+}
+
+pub(crate) fn register_allocator(allocator: Allocator) {
+    match allocator {
+        Allocator::Default => register_default_allocator(),
+        Allocator::Rust { track_statistics } => register_rust_allocator(track_statistics),
+    }
+}
+
+pub(crate) fn register_default_allocator() {
     unsafe {
         jolt_sys::JPC_RegisterDefaultAllocator();
     }
 }
 
+fn register_rust_allocator(track_statistics: bool) {
+    TRACK_STATISTICS.store(track_statistics, Ordering::Relaxed);
+    unsafe {
+        if track_statistics {
+            jolt_sys::JPC_RegisterCustomAllocator(
+                Some(rust_allocate::<true>),
+                Some(rust_free::<true>),
+                Some(rust_aligned_allocate::<true>),
+                Some(rust_free::<true>),
+            );
+        } else {
+            jolt_sys::JPC_RegisterCustomAllocator(
+                Some(rust_allocate::<false>),
+                Some(rust_free::<false>),
+                Some(rust_aligned_allocate::<false>),
+                Some(rust_free::<false>),
+            );
+        }
+    }
+}
+
+// Jolt's free functions don't pass the size back, so every block is prefixed with a header
+// holding the requested size and alignment. The header is padded to the block's alignment so the
+// returned pointer stays aligned.
+const MIN_ALIGNMENT: usize = 16;
+
+fn header_size(align: usize) -> usize {
+    align.max(MIN_ALIGNMENT)
+}
+
+/// Layout of the whole block including the header, `None` if it would overflow.
+fn block_layout(size: usize, align: usize) -> Option<(Layout, usize)> {
+    let header = header_size(align);
+    let layout = Layout::from_size_align(size.checked_add(header)?, align).ok()?;
+    Some((layout, header))
+}
+
+unsafe extern "C" fn rust_allocate<const TRACK: bool>(size: usize) -> *mut c_void {
+    rust_aligned_allocate::<TRACK>(size, MIN_ALIGNMENT)
+}
+
+unsafe extern "C" fn rust_aligned_allocate<const TRACK: bool>(
+    size: usize,
+    align: usize,
+) -> *mut c_void {
+    let align = align.max(MIN_ALIGNMENT);
+    let Some((layout, header)) = block_layout(size, align) else {
+        return std::ptr::null_mut();
+    };
+
+    let base = std::alloc::alloc(layout);
+    if base.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let block = base.add(header);
+    let meta = block.cast::<usize>();
+    meta.sub(1).write(size);
+    meta.sub(2).write(align);
+
+    if TRACK {
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+        LIVE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        TOTAL_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    block.cast()
+}
+
+unsafe extern "C" fn rust_free<const TRACK: bool>(block: *mut c_void) {
+    if block.is_null() {
+        return;
+    }
+
+    let meta = block.cast::<usize>();
+    let size = meta.sub(1).read();
+    let align = meta.sub(2).read();
+    // Validated when the block was allocated.
+    let (layout, header) = block_layout(size, align).unwrap_unchecked();
+
+    if TRACK {
+        LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+        LIVE_ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    let base = block.cast::<u8>().sub(header);
+    std::alloc::dealloc(base, layout);
+}
+
+/// Usage of a [`TempAllocator`], in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TempAllocatorStats {
+    /// Size of the arena.
+    pub capacity: u32,
+    /// Currently allocated, this is 0 outside of `PhysicsSystem::update`.
+    pub usage: u32,
+    /// Peak usage during the last `PhysicsSystem::update`.
+    pub high_water_mark: u32,
+    /// Allocations during the last `PhysicsSystem::update` that didn't fit the arena.
+    pub fallback_allocations: u32,
+}
+
+/// Scratch memory used by Jolt during `PhysicsSystem::update`.
 pub struct TempAllocator(*mut jolt_sys::JPC_TempAllocator);
 
 impl TempAllocator {
-    pub fn create(size: u32) -> Self {
-        unsafe { TempAllocator(jolt_sys::JPC_TempAllocator_Create(size)) }
+    /// Creates a fixed-size arena. Jolt aborts with an out of memory message if an update needs
+    /// more than `size` bytes, which with a `size` of 0 is the first one.
+    pub fn create(_runtime: &JoltRuntime, size: u32) -> Self {
+        Self::with_strategy(
+            jolt_sys::JPC_ETempAllocatorStrategy_JPC_TEMP_ALLOCATOR_FIXED,
+            size,
+        )
+    }
+
+    /// Creates an arena that falls back to Jolt's allocator when it is full. With a `size` of 0
+    /// every allocation goes to the fallback.
+    pub fn with_malloc_fallback(_runtime: &JoltRuntime, size: u32) -> Self {
+        Self::with_strategy(
+            jolt_sys::JPC_ETempAllocatorStrategy_JPC_TEMP_ALLOCATOR_FIXED_MALLOC_FALLBACK,
+            size,
+        )
+    }
+
+    /// Creates an arena that falls back to Jolt's allocator when it is full. At the start of the
+    /// next `PhysicsSystem::update` the arena grows to the previous step's high water mark, so
+    /// only the first steps that exceed it pay for fallback allocations.
+    pub fn growable(_runtime: &JoltRuntime, initial_size: u32) -> Self {
+        Self::with_strategy(
+            jolt_sys::JPC_ETempAllocatorStrategy_JPC_TEMP_ALLOCATOR_GROWABLE,
+            initial_size,
+        )
+    }
+
+    fn with_strategy(strategy: jolt_sys::JPC_ETempAllocatorStrategy, size: u32) -> Self {
+        unsafe {
+            TempAllocator(jolt_sys::JPC_TempAllocator_CreateWithStrategy(
+                strategy as _,
+                size,
+            ))
+        }
+    }
+
+    pub fn stats(&self) -> TempAllocatorStats {
+        let mut stats = jolt_sys::JPC_TempAllocatorStats {
+            capacity: 0,
+            usage: 0,
+            high_water_mark: 0,
+            fallback_allocations: 0,
+        };
+        unsafe {
+            jolt_sys::JPC_TempAllocator_GetStats(self.0, &mut stats);
+        }
+
+        TempAllocatorStats {
+            capacity: stats.capacity,
+            usage: stats.usage,
+            high_water_mark: stats.high_water_mark,
+            fallback_allocations: stats.fallback_allocations,
+        }
+    }
+
+    pub(crate) fn reset_stats(&mut self) {
+        unsafe {
+            jolt_sys::JPC_TempAllocator_ResetStats(self.0);
+        }
     }
 
     pub(crate) fn as_ptr(&self) -> *mut jolt_sys::JPC_TempAllocator {
         self.0
     }
 }
+
+impl Drop for TempAllocator {
+    fn drop(&mut self) {
+        unsafe {
+            jolt_sys::JPC_TempAllocator_Destroy(self.0);
+        }
+    }
+}
+
+unsafe impl Send for TempAllocator {}
@@ -1,15 +1,23 @@
 mod assert;
 mod configuration;
+mod error;
 mod factory;
 mod job_system;
 mod memory;
+mod panic;
 mod registry;
+mod runtime;
+mod user_data;
 mod vec3;
 
 pub use assert::*;
 pub use configuration::*;
-pub use factory::*;
+pub use error::*;
+pub(crate) use factory::*;
 pub use job_system::*;
 pub use memory::*;
-pub use registry::*;
-pub use vec3::Vec3Ext;
+pub(crate) use panic::*;
+pub(crate) use registry::*;
+pub use runtime::*;
+pub use user_data::*;
+pub use vec3::{RVec3Ext, Real, Transform, Vec3Ext};
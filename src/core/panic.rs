@@ -0,0 +1,44 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+};
+
+/// Holds the first panic raised by a Rust callback that Jolt called into.
+///
+/// Unwinding through Jolt's C++ frames is undefined behavior, so trampolines catch panics, stash
+/// them here and return a fallback value. The owner re-raises the panic once control is back in
+/// Rust.
+#[derive(Default)]
+pub(crate) struct CallbackPanic(Mutex<Option<Box<dyn Any + Send>>>);
+
+impl CallbackPanic {
+    pub(crate) fn catch<R>(&self, fallback: R, f: impl FnOnce() -> R) -> R {
+        match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(result) => result,
+            Err(payload) => {
+                let mut slot = self.0.lock().unwrap_or_else(|e| e.into_inner());
+                if slot.is_none() {
+                    *slot = Some(payload);
+                }
+                fallback
+            }
+        }
+    }
+
+    /// Re-raises the stored panic, if any.
+    pub(crate) fn resume(&self) {
+        let payload = self.0.lock().unwrap_or_else(|e| e.into_inner()).take();
+        if let Some(payload) = payload {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// For callbacks that have nowhere to hand a panic back to, aborts instead of unwinding into C++.
+pub(crate) fn abort_on_panic<R>(f: impl FnOnce() -> R) -> R {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(_) => std::process::abort(),
+    }
+}
@@ -1,4 +1,4 @@
-pub fn register_types() {
+pub(crate) fn register_types() {
     unsafe {
         jolt_sys::JPC_RegisterTypes();
     }
@@ -0,0 +1,94 @@
+use crate::{
+    create_factory, destroy_factory, register_allocator, register_types, verify_features, Allocator,
+};
+use std::sync::{Mutex, OnceLock};
+
+static RUNTIME_REFCOUNT: Mutex<usize> = Mutex::new(0);
+// Blocks allocated by Jolt can outlive a runtime (e.g. statics inside Jolt), so the allocator can
+// only be chosen once per process.
+static REGISTERED_ALLOCATOR: OnceLock<Allocator> = OnceLock::new();
+
+/// Keeps Jolt's global state (allocator, factory and registered types) alive.
+///
+/// The first guard performs the initialization, dropping the last one tears it down again.
+/// APIs that need an initialized Jolt take a `&JoltRuntime` as proof that this has happened.
+///
+/// Everything that allocates through Jolt needs one, so it can't be created before Jolt's
+/// allocator is registered:
+///
+/// ```compile_fail
+/// let shape_settings = jolt::BoxShapeSettings::new([0.5, 0.5, 0.5]);
+/// let temp_allocator = jolt::TempAllocator::create(10 * 1024 * 1024);
+/// ```
+///
+/// ```no_run
+/// let runtime = jolt::JoltRuntime::init();
+/// let shape_settings = jolt::BoxShapeSettings::new(&runtime, [0.5, 0.5, 0.5]);
+/// let temp_allocator = jolt::TempAllocator::create(&runtime, 10 * 1024 * 1024);
+/// ```
+pub struct JoltRuntime {
+    _private: (),
+}
+
+impl JoltRuntime {
+    pub fn init() -> Self {
+        Self::init_with_allocator(Allocator::Default)
+    }
+
+    /// Initializes Jolt with the given allocator.
+    ///
+    /// Panics if Jolt was already initialized with a different allocator earlier in the process.
+    pub fn init_with_allocator(allocator: Allocator) -> Self {
+        let mut refcount = RUNTIME_REFCOUNT.lock().unwrap_or_else(|e| e.into_inner());
+        let registered = *REGISTERED_ALLOCATOR.get_or_init(|| {
+            register_allocator(allocator);
+            allocator
+        });
+        assert_eq!(
+            registered, allocator,
+            "Jolt was already initialized with a different allocator"
+        );
+
+        if *refcount == 0 {
+            if let Err(e) = verify_features() {
+                panic!("{e}");
+            }
+
+            create_factory();
+            register_types();
+        }
+        *refcount += 1;
+
+        Self { _private: () }
+    }
+
+    /// Takes another reference on an already initialized runtime, for objects handed out by Jolt
+    /// that didn't get a `&JoltRuntime` passed in.
+    pub(crate) fn acquire() -> Self {
+        let mut refcount = RUNTIME_REFCOUNT.lock().unwrap_or_else(|e| e.into_inner());
+        assert!(*refcount > 0, "Jolt is not initialized");
+        *refcount += 1;
+        Self { _private: () }
+    }
+
+    pub fn is_initialized() -> bool {
+        *RUNTIME_REFCOUNT.lock().unwrap_or_else(|e| e.into_inner()) > 0
+    }
+}
+
+impl Clone for JoltRuntime {
+    fn clone(&self) -> Self {
+        *RUNTIME_REFCOUNT.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+        Self { _private: () }
+    }
+}
+
+impl Drop for JoltRuntime {
+    fn drop(&mut self) {
+        let mut refcount = RUNTIME_REFCOUNT.lock().unwrap_or_else(|e| e.into_inner());
+        *refcount -= 1;
+        if *refcount == 0 {
+            destroy_factory();
+        }
+    }
+}
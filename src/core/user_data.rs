@@ -0,0 +1,183 @@
+use std::fmt;
+
+/// Handle to a value in a [`UserDataMap`], which packs into the `u64` user data Jolt stores on
+/// bodies, shapes and constraints.
+///
+/// The generation is never 0, so a key never encodes to the default user data of 0.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UserDataKey {
+    index: u32,
+    generation: u32,
+}
+
+impl UserDataKey {
+    pub fn to_user_data(self) -> u64 {
+        (self.generation as u64) << 32 | self.index as u64
+    }
+
+    /// Unpacks a key, it's only checked against a map on lookup.
+    pub fn from_user_data(user_data: u64) -> Self {
+        Self {
+            index: user_data as u32,
+            generation: (user_data >> 32) as u32,
+        }
+    }
+}
+
+impl fmt::Debug for UserDataKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UserDataKey({}v{})", self.index, self.generation)
+    }
+}
+
+impl From<UserDataKey> for u64 {
+    fn from(key: UserDataKey) -> Self {
+        key.to_user_data()
+    }
+}
+
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+impl<T> Slot<T> {
+    fn retire(&mut self) {
+        // Skip 0 on wrap-around so keys keep encoding to non-zero user data
+        self.generation = self.generation.checked_add(1).unwrap_or(1);
+    }
+}
+
+/// Maps Jolt user data back to your own values, e.g. game entities.
+///
+/// Removing a value bumps the generation of its slot, so stale user data left on a body (or
+/// delivered late by a listener) resolves to `None` instead of whatever reused the slot.
+pub struct UserDataMap<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+    len: usize,
+}
+
+impl<T> UserDataMap<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// # Panics
+    /// If the map already holds `u32::MAX` slots.
+    pub fn insert(&mut self, value: T) -> UserDataKey {
+        self.len += 1;
+
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index as usize];
+            slot.value = Some(value);
+            return UserDataKey {
+                index,
+                generation: slot.generation,
+            };
+        }
+
+        let index = u32::try_from(self.slots.len()).expect("UserDataMap is full");
+        self.slots.push(Slot {
+            generation: 1,
+            value: Some(value),
+        });
+        UserDataKey {
+            index,
+            generation: 1,
+        }
+    }
+
+    pub fn remove(&mut self, key: UserDataKey) -> Option<T> {
+        let slot = self.slots.get_mut(key.index as usize)?;
+        if slot.generation != key.generation {
+            return None;
+        }
+
+        let value = slot.value.take()?;
+        slot.retire();
+        self.free.push(key.index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn contains(&self, key: UserDataKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn get(&self, key: UserDataKey) -> Option<&T> {
+        self.slots
+            .get(key.index as usize)
+            .filter(|slot| slot.generation == key.generation)?
+            .value
+            .as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: UserDataKey) -> Option<&mut T> {
+        self.slots
+            .get_mut(key.index as usize)
+            .filter(|slot| slot.generation == key.generation)?
+            .value
+            .as_mut()
+    }
+
+    /// Looks up user data as handed out by Jolt, e.g. from
+    /// [`BodyRef::user_data`](crate::BodyRef::user_data) or a
+    /// [`BodyActivationListener`](crate::BodyActivationListener).
+    pub fn resolve(&self, user_data: u64) -> Option<&T> {
+        self.get(UserDataKey::from_user_data(user_data))
+    }
+
+    pub fn resolve_mut(&mut self, user_data: u64) -> Option<&mut T> {
+        self.get_mut(UserDataKey::from_user_data(user_data))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (UserDataKey, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let key = UserDataKey {
+                index: index as u32,
+                generation: slot.generation,
+            };
+            slot.value.as_ref().map(|value| (key, value))
+        })
+    }
+
+    /// Removes all values, keys handed out before stay invalid.
+    pub fn clear(&mut self) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot.value.take().is_some() {
+                slot.retire();
+                self.free.push(index as u32);
+            }
+        }
+        self.len = 0;
+    }
+}
+
+impl<T> Default for UserDataMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UserDataMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
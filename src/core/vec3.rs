@@ -1,4 +1,23 @@
-use mint::Vector3;
+use mint::{ColumnMatrix3, Point3, Vector3};
+
+/// Scalar type of world-space positions, `f64` when Jolt is built with double precision.
+pub type Real = jolt_sys::JPC_Real;
+
+/// A rigid transform as returned by Jolt, the rotation matrix is stored column-major.
+#[derive(Debug, Clone, Copy)]
+pub struct Transform {
+    pub rotation: ColumnMatrix3<f32>,
+    pub translation: Point3<Real>,
+}
+
+impl Transform {
+    pub(crate) fn from_raw(rotation: [f32; 9], translation: [Real; 3]) -> Self {
+        Self {
+            rotation: ColumnMatrix3::from(rotation),
+            translation: Point3::from(translation),
+        }
+    }
+}
 
 pub trait Vec3Ext {
     fn to_fixed_vec3(self) -> [f32; 4];
@@ -13,3 +32,17 @@ where
         [v.x, v.y, v.z, v.z]
     }
 }
+
+pub trait RVec3Ext {
+    fn to_fixed_rvec3(self) -> [Real; 4];
+}
+
+impl<V> RVec3Ext for V
+where
+    V: Into<Vector3<Real>>,
+{
+    fn to_fixed_rvec3(self) -> [Real; 4] {
+        let v = self.into();
+        [v.x, v.y, v.z, v.z]
+    }
+}
@@ -1,21 +1,23 @@
-use crate::BodyId;
+use crate::{BodyId, CallbackPanic};
 use jolt_sys::JPC_BodyID;
-use std::ffi::c_void;
+use std::{ffi::c_void, sync::Arc};
 
 // JPC_BodyActivationListenerVTable
-pub trait BodyActivationListener {
+/// Called from Jolt's worker threads, and from whichever thread activates a body.
+pub trait BodyActivationListener: Send + Sync {
     fn on_body_activated(&self, body_id: BodyId, user_data: u64);
     fn on_body_deactivated(&self, body_id: BodyId, user_data: u64);
 }
 
 #[repr(C)]
 pub struct BodyActivationListenerWrapper {
-    pub(crate) vtable: *const jolt_sys::JPC_BodyActivationListenerVTable,
+    pub(crate) vtable: Box<jolt_sys::JPC_BodyActivationListenerVTable>,
     pub(crate) inner: Box<dyn BodyActivationListener>,
+    pub(crate) panic: Arc<CallbackPanic>,
 }
 
 impl BodyActivationListenerWrapper {
-    pub fn new(inner: Box<dyn BodyActivationListener>) -> Self {
+    pub(crate) fn new(inner: Box<dyn BodyActivationListener>, panic: Arc<CallbackPanic>) -> Self {
         let vtable = Box::new(jolt_sys::JPC_BodyActivationListenerVTable {
             __vtable_header: unsafe { std::mem::zeroed() },
             OnBodyActivated: Some(Self::on_body_activated),
@@ -23,8 +25,9 @@ impl BodyActivationListenerWrapper {
         });
 
         Self {
-            vtable: Box::into_raw(vtable),
+            vtable,
             inner,
+            panic,
         }
     }
 
@@ -33,9 +36,10 @@ impl BodyActivationListenerWrapper {
         in_body_id: *const JPC_BodyID,
         in_user_data: u64,
     ) {
-        (*(wrapper as *const Self))
-            .inner
-            .on_body_activated(*in_body_id, in_user_data)
+        let wrapper = &*(wrapper as *const Self);
+        wrapper.panic.catch((), || {
+            wrapper.inner.on_body_activated(*in_body_id, in_user_data)
+        })
     }
 
     unsafe extern "C" fn on_body_deactivated(
@@ -43,8 +47,9 @@ impl BodyActivationListenerWrapper {
         in_body_id: *const JPC_BodyID,
         in_user_data: u64,
     ) {
-        (*(wrapper as *const Self))
-            .inner
-            .on_body_deactivated(*in_body_id, in_user_data)
+        let wrapper = &*(wrapper as *const Self);
+        wrapper.panic.catch((), || {
+            wrapper.inner.on_body_deactivated(*in_body_id, in_user_data)
+        })
     }
 }
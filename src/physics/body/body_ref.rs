@@ -0,0 +1,439 @@
+use crate::{
+    core::{RVec3Ext, Vec3Ext},
+    Body, BodyId, BroadPhaseLayer, CollisionGroup, MotionType, ObjectLayer, Real, Result, Shape,
+    SubShapeID, Transform,
+};
+use mint::{ColumnMatrix4, Point3, Quaternion, Vector3};
+use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
+
+/// Shared access to a body, for reading its state.
+///
+/// Deliberately neither `Clone` nor `Copy`: lock guards and [`BodyMut`] hand out a `&BodyRef<'a>`
+/// whose `'a` outlives them, so a copy could be used after the lock was released.
+pub struct BodyRef<'a>(NonNull<Body>, PhantomData<&'a Body>);
+
+impl<'a> BodyRef<'a> {
+    /// # Safety
+    /// `body` must point to a valid body that isn't modified or destroyed for the lifetime `'a`.
+    pub unsafe fn from_raw(body: *const Body) -> Self {
+        Self(
+            NonNull::new(body as *mut Body).expect("Body pointer is NULL"),
+            PhantomData,
+        )
+    }
+
+    pub fn as_raw(&self) -> *const Body {
+        self.0.as_ptr()
+    }
+
+    pub fn id(&self) -> BodyId {
+        unsafe { jolt_sys::JPC_Body_GetID(self.as_raw()) }
+    }
+
+    pub fn is_active(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_IsActive(self.as_raw()) }
+    }
+
+    pub fn is_static(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_IsStatic(self.as_raw()) }
+    }
+
+    pub fn is_kinematic(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_IsKinematic(self.as_raw()) }
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_IsDynamic(self.as_raw()) }
+    }
+
+    pub fn can_be_kinematic_or_dynamic(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_CanBeKinematicOrDynamic(self.as_raw()) }
+    }
+
+    pub fn is_sensor(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_IsSensor(self.as_raw()) }
+    }
+
+    pub fn motion_type(&self) -> Result<MotionType> {
+        unsafe { jolt_sys::JPC_Body_GetMotionType(self.as_raw()) }.try_into()
+    }
+
+    pub fn broad_phase_layer(&self) -> BroadPhaseLayer {
+        unsafe { jolt_sys::JPC_Body_GetBroadPhaseLayer(self.as_raw()) }
+    }
+
+    pub fn object_layer(&self) -> ObjectLayer {
+        unsafe { jolt_sys::JPC_Body_GetObjectLayer(self.as_raw()) }
+    }
+
+    pub fn collision_group(&self) -> &CollisionGroup {
+        unsafe {
+            let group = jolt_sys::JPC_Body_GetCollisionGroup(self.as_raw() as *mut Body);
+            CollisionGroup::from_raw_ref(group)
+        }
+    }
+
+    pub fn allow_sleeping(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_GetAllowSleeping(self.as_raw()) }
+    }
+
+    pub fn friction(&self) -> f32 {
+        unsafe { jolt_sys::JPC_Body_GetFriction(self.as_raw()) }
+    }
+
+    pub fn restitution(&self) -> f32 {
+        unsafe { jolt_sys::JPC_Body_GetRestitution(self.as_raw()) }
+    }
+
+    pub fn linear_velocity(&self) -> Vector3<f32> {
+        unsafe {
+            let mut result = [0.; 3];
+            jolt_sys::JPC_Body_GetLinearVelocity(self.as_raw(), result.as_mut_ptr());
+            Vector3::from(result)
+        }
+    }
+
+    pub fn angular_velocity(&self) -> Vector3<f32> {
+        unsafe {
+            let mut result = [0.; 3];
+            jolt_sys::JPC_Body_GetAngularVelocity(self.as_raw(), result.as_mut_ptr());
+            Vector3::from(result)
+        }
+    }
+
+    /// Velocity of a point relative to the center of mass.
+    pub fn point_velocity_com(&self, point: impl Into<Vector3<f32>>) -> Vector3<f32> {
+        let point = point.into().to_fixed_vec3();
+        unsafe {
+            let mut result = [0.; 3];
+            jolt_sys::JPC_Body_GetPointVelocityCOM(
+                self.as_raw(),
+                point.as_ptr(),
+                result.as_mut_ptr(),
+            );
+            Vector3::from(result)
+        }
+    }
+
+    /// Velocity of a point in world space.
+    pub fn point_velocity(&self, point: impl Into<Point3<Real>>) -> Vector3<f32> {
+        let point = point.into().to_fixed_rvec3();
+        unsafe {
+            let mut result = [0.; 3];
+            jolt_sys::JPC_Body_GetPointVelocity(self.as_raw(), point.as_ptr(), result.as_mut_ptr());
+            Vector3::from(result)
+        }
+    }
+
+    pub fn inverse_inertia(&self) -> ColumnMatrix4<f32> {
+        unsafe {
+            let mut result = [0.; 16];
+            jolt_sys::JPC_Body_GetInverseInertia(self.as_raw(), result.as_mut_ptr());
+            ColumnMatrix4::from(result)
+        }
+    }
+
+    pub fn is_in_broad_phase(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_IsInBroadPhase(self.as_raw()) }
+    }
+
+    pub fn is_collision_cache_invalid(&self) -> bool {
+        unsafe { jolt_sys::JPC_Body_IsCollisionCacheInvalid(self.as_raw()) }
+    }
+
+    pub fn shape(&self) -> Shape {
+        unsafe {
+            let shape = jolt_sys::JPC_Body_GetShape(self.as_raw()) as *mut jolt_sys::JPC_Shape;
+            jolt_sys::JPC_Shape_AddRef(shape);
+            Shape::from_raw(shape)
+        }
+    }
+
+    pub fn position(&self) -> Point3<Real> {
+        unsafe {
+            let mut result = [0.; 3];
+            jolt_sys::JPC_Body_GetPosition(self.as_raw(), result.as_mut_ptr());
+            Point3::from(result)
+        }
+    }
+
+    pub fn rotation(&self) -> Quaternion<f32> {
+        unsafe {
+            let mut result = [0., 0., 0., 1.];
+            jolt_sys::JPC_Body_GetRotation(self.as_raw(), result.as_mut_ptr());
+            Quaternion::from(result)
+        }
+    }
+
+    pub fn world_transform(&self) -> Transform {
+        unsafe {
+            let mut rotation = [0.; 9];
+            let mut translation = [0.; 3];
+            jolt_sys::JPC_Body_GetWorldTransform(
+                self.as_raw(),
+                rotation.as_mut_ptr(),
+                translation.as_mut_ptr(),
+            );
+            Transform::from_raw(rotation, translation)
+        }
+    }
+
+    pub fn center_of_mass_position(&self) -> Point3<Real> {
+        unsafe {
+            let mut result = [0.; 3];
+            jolt_sys::JPC_Body_GetCenterOfMassPosition(self.as_raw(), result.as_mut_ptr());
+            Point3::from(result)
+        }
+    }
+
+    pub fn center_of_mass_transform(&self) -> Transform {
+        unsafe {
+            let mut rotation = [0.; 9];
+            let mut translation = [0.; 3];
+            jolt_sys::JPC_Body_GetCenterOfMassTransform(
+                self.as_raw(),
+                rotation.as_mut_ptr(),
+                translation.as_mut_ptr(),
+            );
+            Transform::from_raw(rotation, translation)
+        }
+    }
+
+    pub fn inverse_center_of_mass_transform(&self) -> Transform {
+        unsafe {
+            let mut rotation = [0.; 9];
+            let mut translation = [0.; 3];
+            jolt_sys::JPC_Body_GetInverseCenterOfMassTransform(
+                self.as_raw(),
+                rotation.as_mut_ptr(),
+                translation.as_mut_ptr(),
+            );
+            Transform::from_raw(rotation, translation)
+        }
+    }
+
+    /// Returns the minimum and maximum corners of the body's world space bounding box.
+    pub fn world_space_bounds(&self) -> (Point3<f32>, Point3<f32>) {
+        unsafe {
+            let mut min = [0.; 3];
+            let mut max = [0.; 3];
+            jolt_sys::JPC_Body_GetWorldSpaceBounds(
+                self.as_raw(),
+                min.as_mut_ptr(),
+                max.as_mut_ptr(),
+            );
+            (Point3::from(min), Point3::from(max))
+        }
+    }
+
+    pub fn user_data(&self) -> u64 {
+        unsafe { jolt_sys::JPC_Body_GetUserData(self.as_raw()) }
+    }
+
+    pub fn world_space_surface_normal(
+        &self,
+        sub_shape_id: SubShapeID,
+        position: impl Into<Point3<Real>>,
+    ) -> Vector3<f32> {
+        let position = position.into().to_fixed_rvec3();
+        unsafe {
+            let mut result = [0.; 3];
+            jolt_sys::JPC_Body_GetWorldSpaceSurfaceNormal(
+                self.as_raw(),
+                sub_shape_id,
+                position.as_ptr(),
+                result.as_mut_ptr(),
+            );
+            Vector3::from(result)
+        }
+    }
+}
+
+impl<'a> From<&'a Body> for BodyRef<'a> {
+    fn from(body: &'a Body) -> Self {
+        unsafe { Self::from_raw(body) }
+    }
+}
+
+/// Exclusive access to a body, for changing its state. Derefs to [`BodyRef`] for reading.
+pub struct BodyMut<'a>(BodyRef<'a>);
+
+impl<'a> BodyMut<'a> {
+    /// # Safety
+    /// `body` must point to a valid body that isn't accessed through any other pointer or
+    /// destroyed for the lifetime `'a`.
+    pub unsafe fn from_raw(body: *mut Body) -> Self {
+        Self(BodyRef::from_raw(body))
+    }
+
+    pub fn as_raw_mut(&mut self) -> *mut Body {
+        self.0 .0.as_ptr()
+    }
+
+    pub fn set_is_sensor(&mut self, is_sensor: bool) {
+        unsafe { jolt_sys::JPC_Body_SetIsSensor(self.as_raw_mut(), is_sensor) }
+    }
+
+    pub fn set_motion_type(&mut self, motion_type: MotionType) {
+        unsafe { jolt_sys::JPC_Body_SetMotionType(self.as_raw_mut(), motion_type as _) }
+    }
+
+    pub fn set_collision_group(&mut self, group: &CollisionGroup) {
+        unsafe { jolt_sys::JPC_Body_SetCollisionGroup(self.as_raw_mut(), &group.as_raw()) }
+    }
+
+    pub fn set_allow_sleeping(&mut self, allow_sleeping: bool) {
+        unsafe { jolt_sys::JPC_Body_SetAllowSleeping(self.as_raw_mut(), allow_sleeping) }
+    }
+
+    pub fn set_friction(&mut self, friction: f32) {
+        unsafe { jolt_sys::JPC_Body_SetFriction(self.as_raw_mut(), friction) }
+    }
+
+    pub fn set_restitution(&mut self, restitution: f32) {
+        unsafe { jolt_sys::JPC_Body_SetRestitution(self.as_raw_mut(), restitution) }
+    }
+
+    pub fn set_linear_velocity(&mut self, velocity: impl Into<Vector3<f32>>) {
+        let velocity = velocity.into().to_fixed_vec3();
+        unsafe { jolt_sys::JPC_Body_SetLinearVelocity(self.as_raw_mut(), velocity.as_ptr()) }
+    }
+
+    /// Sets the linear velocity, clamped to the body's maximum linear velocity.
+    pub fn set_linear_velocity_clamped(&mut self, velocity: impl Into<Vector3<f32>>) {
+        let velocity = velocity.into().to_fixed_vec3();
+        unsafe { jolt_sys::JPC_Body_SetLinearVelocityClamped(self.as_raw_mut(), velocity.as_ptr()) }
+    }
+
+    pub fn set_angular_velocity(&mut self, velocity: impl Into<Vector3<f32>>) {
+        let velocity = velocity.into().to_fixed_vec3();
+        unsafe { jolt_sys::JPC_Body_SetAnglularVelocity(self.as_raw_mut(), velocity.as_ptr()) }
+    }
+
+    /// Sets the angular velocity, clamped to the body's maximum angular velocity.
+    pub fn set_angular_velocity_clamped(&mut self, velocity: impl Into<Vector3<f32>>) {
+        let velocity = velocity.into().to_fixed_vec3();
+        unsafe {
+            jolt_sys::JPC_Body_SetAnglularVelocityClamped(self.as_raw_mut(), velocity.as_ptr())
+        }
+    }
+
+    pub fn add_force(&mut self, force: impl Into<Vector3<f32>>) {
+        let force = force.into().to_fixed_vec3();
+        unsafe { jolt_sys::JPC_Body_AddForce(self.as_raw_mut(), force.as_ptr()) }
+    }
+
+    pub fn add_force_at_position(
+        &mut self,
+        force: impl Into<Vector3<f32>>,
+        position: impl Into<Point3<Real>>,
+    ) {
+        let force = force.into().to_fixed_vec3();
+        let position = position.into().to_fixed_rvec3();
+        unsafe {
+            jolt_sys::JPC_Body_AddForceAtPosition(
+                self.as_raw_mut(),
+                force.as_ptr(),
+                position.as_ptr(),
+            )
+        }
+    }
+
+    pub fn add_torque(&mut self, torque: impl Into<Vector3<f32>>) {
+        let torque = torque.into().to_fixed_vec3();
+        unsafe { jolt_sys::JPC_Body_AddTorque(self.as_raw_mut(), torque.as_ptr()) }
+    }
+
+    pub fn add_impulse(&mut self, impulse: impl Into<Vector3<f32>>) {
+        let impulse = impulse.into().to_fixed_vec3();
+        unsafe { jolt_sys::JPC_Body_AddImpulse(self.as_raw_mut(), impulse.as_ptr()) }
+    }
+
+    pub fn add_impulse_at_position(
+        &mut self,
+        impulse: impl Into<Vector3<f32>>,
+        position: impl Into<Point3<Real>>,
+    ) {
+        let impulse = impulse.into().to_fixed_vec3();
+        let position = position.into().to_fixed_rvec3();
+        unsafe {
+            jolt_sys::JPC_Body_AddImpulseAtPosition(
+                self.as_raw_mut(),
+                impulse.as_ptr(),
+                position.as_ptr(),
+            )
+        }
+    }
+
+    pub fn add_angular_impulse(&mut self, impulse: impl Into<Vector3<f32>>) {
+        let impulse = impulse.into().to_fixed_vec3();
+        unsafe { jolt_sys::JPC_Body_AddAngularImpulse(self.as_raw_mut(), impulse.as_ptr()) }
+    }
+
+    /// Sets the velocities of a kinematic body so it reaches the target transform in `delta_time`.
+    pub fn move_kinematic(
+        &mut self,
+        target_position: impl Into<Point3<Real>>,
+        target_rotation: impl Into<Quaternion<f32>>,
+        delta_time: f32,
+    ) {
+        let target_position = target_position.into().to_fixed_rvec3();
+        unsafe {
+            jolt_sys::JPC_Body_MoveKinematic(
+                self.as_raw_mut(),
+                target_position.as_ptr(),
+                target_rotation.into().as_ref().as_ptr(),
+                delta_time,
+            )
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_buoyancy_impulse(
+        &mut self,
+        surface_position: impl Into<Point3<Real>>,
+        surface_normal: impl Into<Vector3<f32>>,
+        buoyancy: f32,
+        linear_drag: f32,
+        angular_drag: f32,
+        fluid_velocity: impl Into<Vector3<f32>>,
+        gravity: impl Into<Vector3<f32>>,
+        delta_time: f32,
+    ) {
+        let surface_position = surface_position.into().to_fixed_rvec3();
+        let surface_normal = surface_normal.into().to_fixed_vec3();
+        let fluid_velocity = fluid_velocity.into().to_fixed_vec3();
+        let gravity = gravity.into().to_fixed_vec3();
+        unsafe {
+            jolt_sys::JPC_Body_ApplyBuoyancyImpulse(
+                self.as_raw_mut(),
+                surface_position.as_ptr(),
+                surface_normal.as_ptr(),
+                buoyancy,
+                linear_drag,
+                angular_drag,
+                fluid_velocity.as_ptr(),
+                gravity.as_ptr(),
+                delta_time,
+            )
+        }
+    }
+
+    pub fn set_user_data(&mut self, user_data: u64) {
+        unsafe { jolt_sys::JPC_Body_SetUserData(self.as_raw_mut(), user_data) }
+    }
+}
+
+impl<'a> Deref for BodyMut<'a> {
+    type Target = BodyRef<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a> From<&'a mut Body> for BodyMut<'a> {
+    fn from(body: &'a mut Body) -> Self {
+        unsafe { Self::from_raw(body) }
+    }
+}
@@ -1,14 +1,16 @@
-use crate::{Body, CollideShapeResult, ContactManifold, ContactSettings, SubShapeIDPair};
+use crate::{
+    BodyRef, CallbackPanic, CollideShapeResult, ContactManifold, ContactSettings, Real,
+    SubShapeIDPair,
+};
 use jolt_sys::{
-    JPC_Body, JPC_CollideShapeResult, JPC_ContactManifold, JPC_ContactSettings, JPC_Real,
-    JPC_SubShapeIDPair, JPC_ValidateResult,
-    JPC_ValidateResult_JPC_VALIDATE_RESULT_ACCEPT_ALL_CONTACTS,
+    JPC_Body, JPC_CollideShapeResult, JPC_ContactManifold, JPC_ContactSettings, JPC_SubShapeIDPair,
+    JPC_ValidateResult, JPC_ValidateResult_JPC_VALIDATE_RESULT_ACCEPT_ALL_CONTACTS,
     JPC_ValidateResult_JPC_VALIDATE_RESULT_ACCEPT_CONTACT,
     JPC_ValidateResult_JPC_VALIDATE_RESULT_REJECT_ALL_CONTACTS,
     JPC_ValidateResult_JPC_VALIDATE_RESULT_REJECT_CONTACT,
 };
 use mint::Vector3;
-use std::ffi::c_void;
+use std::{ffi::c_void, sync::Arc};
 
 #[repr(u32)]
 pub enum ValidateResult {
@@ -21,27 +23,28 @@ pub enum ValidateResult {
 }
 
 // JPC_ContactListenerVTable
-pub trait ContactListener {
+/// Called from Jolt's worker threads during [`PhysicsSystem::update`](crate::PhysicsSystem::update).
+pub trait ContactListener: Send + Sync {
     fn on_contact_validate(
         &self,
-        body1: &Body,
-        body2: &Body,
-        base_offset: Vector3<f32>,
+        body1: BodyRef,
+        body2: BodyRef,
+        base_offset: Vector3<Real>,
         collision_result: &CollideShapeResult,
     ) -> ValidateResult;
 
     fn on_contact_added(
         &self,
-        body1: &Body,
-        body2: &Body,
+        body1: BodyRef,
+        body2: BodyRef,
         manifold: &ContactManifold,
         io_settings: &mut ContactSettings,
     );
 
     fn on_contact_persisted(
         &self,
-        body1: &Body,
-        body2: &Body,
+        body1: BodyRef,
+        body2: BodyRef,
         manifold: &ContactManifold,
         io_settings: &mut ContactSettings,
     );
@@ -51,12 +54,13 @@ pub trait ContactListener {
 
 #[repr(C)]
 pub struct ContactListenerWrapper {
-    pub(crate) vtable: *const jolt_sys::JPC_ContactListenerVTable,
+    pub(crate) vtable: Box<jolt_sys::JPC_ContactListenerVTable>,
     pub(crate) inner: Box<dyn ContactListener>,
+    pub(crate) panic: Arc<CallbackPanic>,
 }
 
 impl ContactListenerWrapper {
-    pub fn new(inner: Box<dyn ContactListener>) -> Self {
+    pub(crate) fn new(inner: Box<dyn ContactListener>, panic: Arc<CallbackPanic>) -> Self {
         let vtable = Box::new(jolt_sys::JPC_ContactListenerVTable {
             OnContactValidate: Some(Self::on_contact_validate),
             OnContactAdded: Some(Self::on_contact_added),
@@ -65,8 +69,9 @@ impl ContactListenerWrapper {
         });
 
         Self {
-            vtable: Box::into_raw(vtable),
+            vtable,
             inner,
+            panic,
         }
     }
 
@@ -74,15 +79,20 @@ impl ContactListenerWrapper {
         wrapper: *mut c_void,
         in_body1: *const JPC_Body,
         in_body2: *const JPC_Body,
-        in_base_offset: *const JPC_Real,
+        in_base_offset: *const Real,
         in_collision_result: *const JPC_CollideShapeResult,
     ) -> JPC_ValidateResult {
-        (*(wrapper as *const Self)).inner.on_contact_validate(
-            &*in_body1,
-            &*in_body2,
-            *(in_base_offset as *const Vector3<f32>),
-            &*in_collision_result,
-        ) as _
+        let wrapper = &*(wrapper as *const Self);
+        wrapper
+            .panic
+            .catch(ValidateResult::AcceptAllContactsForThisBodyPair, || {
+                wrapper.inner.on_contact_validate(
+                    BodyRef::from_raw(in_body1),
+                    BodyRef::from_raw(in_body2),
+                    *(in_base_offset as *const Vector3<Real>),
+                    &*in_collision_result,
+                )
+            }) as _
     }
 
     unsafe extern "C" fn on_contact_added(
@@ -92,12 +102,15 @@ impl ContactListenerWrapper {
         in_manifold: *const JPC_ContactManifold,
         io_settings: *mut JPC_ContactSettings,
     ) {
-        (*(wrapper as *const Self)).inner.on_contact_added(
-            &*in_body1,
-            &*in_body2,
-            &*in_manifold,
-            &mut *io_settings,
-        )
+        let wrapper = &*(wrapper as *const Self);
+        wrapper.panic.catch((), || {
+            wrapper.inner.on_contact_added(
+                BodyRef::from_raw(in_body1),
+                BodyRef::from_raw(in_body2),
+                &*in_manifold,
+                &mut *io_settings,
+            )
+        })
     }
 
     unsafe extern "C" fn on_contact_persisted(
@@ -107,20 +120,24 @@ impl ContactListenerWrapper {
         in_manifold: *const JPC_ContactManifold,
         io_settings: *mut JPC_ContactSettings,
     ) {
-        (*(wrapper as *const Self)).inner.on_contact_persisted(
-            &*in_body1,
-            &*in_body2,
-            &*in_manifold,
-            &mut *io_settings,
-        )
+        let wrapper = &*(wrapper as *const Self);
+        wrapper.panic.catch((), || {
+            wrapper.inner.on_contact_persisted(
+                BodyRef::from_raw(in_body1),
+                BodyRef::from_raw(in_body2),
+                &*in_manifold,
+                &mut *io_settings,
+            )
+        })
     }
 
     unsafe extern "C" fn on_contact_removed(
         wrapper: *mut c_void,
         in_sub_shape_pair: *const JPC_SubShapeIDPair,
     ) {
-        (*(wrapper as *const Self))
-            .inner
-            .on_contact_removed(&*in_sub_shape_pair)
+        let wrapper = &*(wrapper as *const Self);
+        wrapper
+            .panic
+            .catch((), || wrapper.inner.on_contact_removed(&*in_sub_shape_pair))
     }
 }
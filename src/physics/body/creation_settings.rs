@@ -1,10 +1,9 @@
-use crate::{AllowedDOFs, MassProperties, MotionQuality, MotionType, ObjectLayer, Shape, Vec3Ext};
-use jolt_sys::{
-    JPC_CollisionGroup,  JPC_ObjectLayer,
-    JPC_Real, JPC_COLLISION_GROUP_INVALID_GROUP, JPC_COLLISION_GROUP_INVALID_SUB_GROUP,
+use crate::{
+    AllowedDOFs, CollisionGroup, MassProperties, MotionQuality, MotionType, ObjectLayer, RVec3Ext,
+    Real, Shape, Vec3Ext,
 };
+use jolt_sys::JPC_ObjectLayer;
 use mint::{Point3, Quaternion, Vector3};
-use std::ptr::null;
 
 pub use jolt_sys::{
     JPC_EOverrideMassProperties_JPC_OVERRIDE_MASS_PROPS_CALC_INERTIA,
@@ -24,13 +23,13 @@ pub enum OverrideMassProperties {
 
 #[derive(Clone)]
 pub struct BodyCreationSettings {
-    pub position: Point3<JPC_Real>,
+    pub position: Point3<Real>,
     pub rotation: Quaternion<f32>,
     pub linear_velocity: Vector3<f32>,
     pub angular_velocity: Vector3<f32>,
     pub user_data: u64,
     pub object_layer: ObjectLayer,
-    pub collision_group: JPC_CollisionGroup,
+    pub collision_group: CollisionGroup,
     pub motion_type: MotionType,
     pub allowed_dofs: AllowedDOFs,
     pub allow_dynamic_or_kinematic: bool,
@@ -65,7 +64,7 @@ impl BodyCreationSettings {
         object_layer: JPC_ObjectLayer,
     ) -> BodyCreationSettings
     where
-        P: Into<Point3<JPC_Real>>,
+        P: Into<Point3<Real>>,
         R: Into<Quaternion<f32>>,
     {
         let p = position.into();
@@ -79,11 +78,7 @@ impl BodyCreationSettings {
             linear_velocity: Vector3::from_slice(&[0f32; 4]),
             angular_velocity: Vector3::from_slice(&[0f32; 4]),
             user_data: 0,
-            collision_group: JPC_CollisionGroup {
-                filter: null(),
-                group_id: JPC_COLLISION_GROUP_INVALID_GROUP,
-                sub_group_id: JPC_COLLISION_GROUP_INVALID_SUB_GROUP,
-            },
+            collision_group: CollisionGroup::default(),
             allowed_dofs: AllowedDOFs::all(),
             allow_dynamic_or_kinematic: true,
             is_sensor: false,
@@ -110,13 +105,13 @@ impl BodyCreationSettings {
 
     pub(crate) fn to_jpc(&self) -> jolt_sys::JPC_BodyCreationSettings {
         jolt_sys::JPC_BodyCreationSettings {
-            position: self.position.to_fixed_vec3(),
+            position: self.position.to_fixed_rvec3(),
             rotation: self.rotation.into(),
             linear_velocity: self.linear_velocity.to_fixed_vec3(),
             angular_velocity: self.angular_velocity.to_fixed_vec3(),
             user_data: self.user_data,
             object_layer: self.object_layer,
-            collision_group: self.collision_group,
+            collision_group: self.collision_group.as_raw(),
             motion_type: self.motion_type as u8,
             allowed_dofs: self.allowed_dofs.bits(),
             allow_dynamic_or_kinematic: self.allow_dynamic_or_kinematic,
@@ -1,3 +1,4 @@
+use crate::impl_enum_try_from;
 use jolt_sys::{
     JPC_EMotionQuality_JPC_MOTION_QUALITY_DISCRETE,
     JPC_EMotionQuality_JPC_MOTION_QUALITY_LINEAR_CAST, JPC_EMotionType_JPC_MOTION_TYPE_DYNAMIC,
@@ -6,12 +7,14 @@ use jolt_sys::{
 
 mod allowed_dofs;
 mod body_activation_listener;
+mod body_ref;
 mod contact_listener;
 mod creation_settings;
 mod mass_properties;
 
 pub use allowed_dofs::*;
 pub use body_activation_listener::*;
+pub use body_ref::*;
 pub use contact_listener::*;
 pub use creation_settings::*;
 pub use mass_properties::*;
@@ -20,7 +23,7 @@ pub type BodyId = jolt_sys::JPC_BodyID;
 pub type Body = jolt_sys::JPC_Body;
 
 #[repr(u8)]
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MotionType {
     Static = JPC_EMotionType_JPC_MOTION_TYPE_STATIC as _,
     Kinematic = JPC_EMotionType_JPC_MOTION_TYPE_KINEMATIC as _,
@@ -28,8 +31,11 @@ pub enum MotionType {
 }
 
 #[repr(u8)]
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MotionQuality {
     Discrete = JPC_EMotionQuality_JPC_MOTION_QUALITY_DISCRETE as _,
     LinearCast = JPC_EMotionQuality_JPC_MOTION_QUALITY_LINEAR_CAST as _,
 }
+
+impl_enum_try_from!(MotionType: jolt_sys::JPC_MotionType { Static, Kinematic, Dynamic });
+impl_enum_try_from!(MotionQuality: jolt_sys::JPC_MotionQuality { Discrete, LinearCast });
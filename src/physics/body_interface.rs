@@ -1,8 +1,13 @@
 use crate::{
-    core::Vec3Ext, Activation, Body, BodyCreationSettings, BodyId, MotionType, ObjectLayer,
+    core::{RVec3Ext, Vec3Ext},
+    Activation, BodyCreationSettings, BodyId, Error, MotionType, ObjectLayer, Real, Result, Shape,
+    Transform, TransformedShape,
+};
+use mint::{ColumnMatrix4, Point3, Quaternion, Vector3};
+use std::{
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
 };
-use mint::{Point3, Quaternion, Vector3};
-use std::{marker::PhantomData, mem::transmute};
 
 pub struct BodyInterface<'a>(*mut jolt_sys::JPC_BodyInterface, PhantomData<&'a ()>);
 
@@ -12,33 +17,41 @@ impl<'a> From<*mut jolt_sys::JPC_BodyInterface> for BodyInterface<'a> {
     }
 }
 
+unsafe impl Send for BodyInterface<'_> {}
+unsafe impl Sync for BodyInterface<'_> {}
+
 impl<'a> BodyInterface<'a> {
-    pub fn create_body(&self, body_settings: &BodyCreationSettings) -> Option<*mut Body> {
-        unsafe {
-            let body_id = jolt_sys::JPC_BodyInterface_CreateBody(self.0, &body_settings.to_jpc());
-            if body_id.is_null() {
-                None
+    /// Creates a body without adding it to the physics system, returning its ID.
+    ///
+    /// Use [`BodyLockInterface::lock_write`](crate::BodyLockInterface::lock_write) to modify the
+    /// body before adding it.
+    pub fn create_body(&self, body_settings: &BodyCreationSettings) -> Result<BodyId> {
+        unsafe {
+            let body = jolt_sys::JPC_BodyInterface_CreateBody(self.0, &body_settings.to_jpc());
+            if body.is_null() {
+                Err(Error::TooManyBodies)
             } else {
-                Some(body_id)
+                Ok(jolt_sys::JPC_Body_GetID(body))
             }
         }
     }
 
+    /// Like [`BodyInterface::create_body`], but with a specific ID, e.g. to restore a snapshot.
     pub fn create_body_with_id(
         &self,
         body_id: BodyId,
         body_settings: &BodyCreationSettings,
-    ) -> Option<*mut Body> {
+    ) -> Result<BodyId> {
         unsafe {
-            let body_id = jolt_sys::JPC_BodyInterface_CreateBodyWithID(
+            let body = jolt_sys::JPC_BodyInterface_CreateBodyWithID(
                 self.0,
                 body_id,
                 &body_settings.to_jpc(),
             );
-            if body_id.is_null() {
-                None
+            if body.is_null() {
+                Err(Error::InvalidBodyId(body_id))
             } else {
-                Some(body_id)
+                Ok(body_id)
             }
         }
     }
@@ -65,13 +78,19 @@ impl<'a> BodyInterface<'a> {
         &self,
         body_settings: &BodyCreationSettings,
         activation: Activation,
-    ) -> BodyId {
-        unsafe {
+    ) -> Result<BodyId> {
+        let body_id = unsafe {
             jolt_sys::JPC_BodyInterface_CreateAndAddBody(
                 self.0,
                 &body_settings.to_jpc(),
                 activation as _,
             )
+        };
+
+        if body_id == jolt_sys::JPC_BODY_ID_INVALID {
+            Err(Error::TooManyBodies)
+        } else {
+            Ok(body_id)
         }
     }
 
@@ -79,6 +98,82 @@ impl<'a> BodyInterface<'a> {
         unsafe { jolt_sys::JPC_BodyInterface_IsAdded(self.0, body_id) }
     }
 
+    /// Prepares a batch of created bodies for insertion into the broadphase.
+    ///
+    /// Jolt may reorder `bodies`. The returned guard aborts the insertion if it's dropped without
+    /// calling [`AddBodiesPrepared::finalize`].
+    ///
+    /// # Panics
+    /// If `bodies` holds more than `i32::MAX` ids.
+    pub fn add_bodies_prepare<'b>(&self, bodies: &'b mut [BodyId]) -> AddBodiesPrepared<'a, 'b> {
+        let state = unsafe {
+            jolt_sys::JPC_BodyInterface_AddBodiesPrepare(
+                self.0,
+                bodies.as_mut_ptr(),
+                batch_len(bodies),
+            )
+        };
+
+        AddBodiesPrepared {
+            body_interface: self.0,
+            bodies,
+            state,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds a batch of created bodies at once, Jolt may reorder `bodies`.
+    ///
+    /// # Panics
+    /// If `bodies` holds more than `i32::MAX` ids.
+    pub fn add_bodies(&self, bodies: &mut [BodyId], activation: Activation) {
+        self.add_bodies_prepare(bodies).finalize(activation);
+    }
+
+    /// Removes a batch of bodies at once, Jolt may reorder `bodies`.
+    ///
+    /// # Panics
+    /// If `bodies` holds more than `i32::MAX` ids.
+    pub fn remove_bodies(&self, bodies: &mut [BodyId]) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_RemoveBodies(
+                self.0,
+                bodies.as_mut_ptr(),
+                batch_len(bodies),
+            );
+        }
+    }
+
+    /// Destroys a batch of bodies, they have to be removed first.
+    ///
+    /// # Panics
+    /// If `bodies` holds more than `i32::MAX` ids.
+    pub fn destroy_bodies(&self, bodies: &[BodyId]) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_DestroyBodies(self.0, bodies.as_ptr(), batch_len(bodies));
+        }
+    }
+
+    /// # Panics
+    /// If `bodies` holds more than `i32::MAX` ids.
+    pub fn activate_bodies(&self, bodies: &[BodyId]) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_ActivateBodies(self.0, bodies.as_ptr(), batch_len(bodies));
+        }
+    }
+
+    /// # Panics
+    /// If `bodies` holds more than `i32::MAX` ids.
+    pub fn deactivate_bodies(&self, bodies: &[BodyId]) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_DeactivateBodies(
+                self.0,
+                bodies.as_ptr(),
+                batch_len(bodies),
+            );
+        }
+    }
+
     pub fn set_linear_and_angular_velocity(
         &self,
         body_id: BodyId,
@@ -171,7 +266,7 @@ impl<'a> BodyInterface<'a> {
     pub fn get_point_velocity(
         &self,
         body_id: BodyId,
-        point: impl Into<Point3<f32>>,
+        point: impl Into<Point3<Real>>,
     ) -> Vector3<f32> {
         unsafe {
             let mut result = [0.; 3];
@@ -186,7 +281,7 @@ impl<'a> BodyInterface<'a> {
         }
     }
 
-    pub fn position(&self, body_id: BodyId) -> Point3<f32> {
+    pub fn position(&self, body_id: BodyId) -> Point3<Real> {
         unsafe {
             let mut result = [0.; 3];
             jolt_sys::JPC_BodyInterface_GetPosition(self.0, body_id, result.as_mut_ptr());
@@ -197,10 +292,10 @@ impl<'a> BodyInterface<'a> {
     pub fn set_position(
         &self,
         body_id: BodyId,
-        position: impl Into<Point3<f32>>,
+        position: impl Into<Point3<Real>>,
         activation: Activation,
     ) {
-        let position = position.into().to_fixed_vec3();
+        let position = position.into().to_fixed_rvec3();
         unsafe {
             jolt_sys::JPC_BodyInterface_SetPosition(
                 self.0,
@@ -211,7 +306,7 @@ impl<'a> BodyInterface<'a> {
         }
     }
 
-    pub fn center_of_mass_position(&self, body_id: BodyId) -> Point3<f32> {
+    pub fn center_of_mass_position(&self, body_id: BodyId) -> Point3<Real> {
         unsafe {
             let mut result = [0.; 3];
             jolt_sys::JPC_BodyInterface_GetCenterOfMassPosition(
@@ -252,12 +347,12 @@ impl<'a> BodyInterface<'a> {
     pub fn set_position_rotation_and_velocity(
         &self,
         body_id: BodyId,
-        position: impl Into<Point3<f32>>,
+        position: impl Into<Point3<Real>>,
         rotation: impl Into<Quaternion<f32>>,
         linear_velocity: impl Into<Vector3<f32>>,
         angular_velocity: impl Into<Vector3<f32>>,
     ) {
-        let position = position.into().to_fixed_vec3();
+        let position = position.into().to_fixed_rvec3();
         let linear_velocity = linear_velocity.into().to_fixed_vec3();
         let angular_velocity = angular_velocity.into().to_fixed_vec3();
         unsafe {
@@ -299,10 +394,10 @@ impl<'a> BodyInterface<'a> {
         &self,
         body_id: BodyId,
         force: impl Into<Vector3<f32>>,
-        position: impl Into<Point3<f32>>,
+        position: impl Into<Point3<Real>>,
     ) {
         let force = force.into().to_fixed_vec3();
-        let position = position.into().to_fixed_vec3();
+        let position = position.into().to_fixed_rvec3();
         unsafe {
             jolt_sys::JPC_BodyInterface_AddForceAtPosition(
                 self.0,
@@ -349,10 +444,10 @@ impl<'a> BodyInterface<'a> {
         &self,
         body_id: BodyId,
         impulse: impl Into<Vector3<f32>>,
-        position: impl Into<Point3<f32>>,
+        position: impl Into<Point3<Real>>,
     ) {
         let impulse = impulse.into().to_fixed_vec3();
-        let position = position.into().to_fixed_vec3();
+        let position = position.into().to_fixed_rvec3();
         unsafe {
             jolt_sys::JPC_BodyInterface_AddImpulseAtPosition(
                 self.0,
@@ -386,9 +481,8 @@ impl<'a> BodyInterface<'a> {
         }
     }
 
-    // TODO(cohae): This is a bit scary, check the return values to cure my paranoia
-    pub fn motion_type(&self, body_id: BodyId) -> MotionType {
-        unsafe { transmute(jolt_sys::JPC_BodyInterface_GetMotionType(self.0, body_id)) }
+    pub fn motion_type(&self, body_id: BodyId) -> Result<MotionType> {
+        unsafe { jolt_sys::JPC_BodyInterface_GetMotionType(self.0, body_id) }.try_into()
     }
 
     pub fn set_object_layer(&self, body_id: BodyId, layer: ObjectLayer) {
@@ -400,4 +494,256 @@ impl<'a> BodyInterface<'a> {
     pub fn object_layer(&self, body_id: BodyId) -> ObjectLayer {
         unsafe { jolt_sys::JPC_BodyInterface_GetObjectLayer(self.0, body_id) }
     }
+
+    /// Returns `None` if the body ID is invalid.
+    pub fn shape(&self, body_id: BodyId) -> Option<Shape> {
+        unsafe {
+            // The C API hands us a new reference
+            let shape = jolt_sys::JPC_BodyInterface_GetShape(self.0, body_id);
+            (!shape.is_null()).then(|| Shape::from_raw(shape as *mut jolt_sys::JPC_Shape))
+        }
+    }
+
+    pub fn set_shape(
+        &self,
+        body_id: BodyId,
+        shape: &Shape,
+        update_mass_properties: bool,
+        activation: Activation,
+    ) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_SetShape(
+                self.0,
+                body_id,
+                shape.as_raw(),
+                update_mass_properties,
+                activation as _,
+            );
+        }
+    }
+
+    /// Notifies Jolt that the shape of a body was modified in place (e.g. a mutable compound).
+    pub fn notify_shape_changed(
+        &self,
+        body_id: BodyId,
+        previous_center_of_mass: impl Into<Vector3<f32>>,
+        update_mass_properties: bool,
+        activation: Activation,
+    ) {
+        let previous_center_of_mass = previous_center_of_mass.into().to_fixed_vec3();
+        unsafe {
+            jolt_sys::JPC_BodyInterface_NotifyShapeChanged(
+                self.0,
+                body_id,
+                previous_center_of_mass.as_ptr(),
+                update_mass_properties,
+                activation as _,
+            );
+        }
+    }
+
+    pub fn set_position_and_rotation(
+        &self,
+        body_id: BodyId,
+        position: impl Into<Point3<Real>>,
+        rotation: impl Into<Quaternion<f32>>,
+        activation: Activation,
+    ) {
+        let position = position.into().to_fixed_rvec3();
+        unsafe {
+            jolt_sys::JPC_BodyInterface_SetPositionAndRotation(
+                self.0,
+                body_id,
+                position.as_ptr(),
+                rotation.into().as_ref().as_ptr(),
+                activation as _,
+            );
+        }
+    }
+
+    /// Like [`Self::set_position_and_rotation`], but only updates the body (and wakes it up) if
+    /// the position or rotation actually changed.
+    pub fn set_position_and_rotation_when_changed(
+        &self,
+        body_id: BodyId,
+        position: impl Into<Point3<Real>>,
+        rotation: impl Into<Quaternion<f32>>,
+        activation: Activation,
+    ) {
+        let position = position.into().to_fixed_rvec3();
+        unsafe {
+            jolt_sys::JPC_BodyInterface_SetPositionAndRotationWhenChanged(
+                self.0,
+                body_id,
+                position.as_ptr(),
+                rotation.into().as_ref().as_ptr(),
+                activation as _,
+            );
+        }
+    }
+
+    pub fn world_transform(&self, body_id: BodyId) -> Transform {
+        unsafe {
+            let mut rotation = [0.; 9];
+            let mut translation = [0.; 3];
+            jolt_sys::JPC_BodyInterface_GetWorldTransform(
+                self.0,
+                body_id,
+                rotation.as_mut_ptr(),
+                translation.as_mut_ptr(),
+            );
+            Transform::from_raw(rotation, translation)
+        }
+    }
+
+    pub fn center_of_mass_transform(&self, body_id: BodyId) -> Transform {
+        unsafe {
+            let mut rotation = [0.; 9];
+            let mut translation = [0.; 3];
+            jolt_sys::JPC_BodyInterface_GetCenterOfMassTransform(
+                self.0,
+                body_id,
+                rotation.as_mut_ptr(),
+                translation.as_mut_ptr(),
+            );
+            Transform::from_raw(rotation, translation)
+        }
+    }
+
+    pub fn move_kinematic(
+        &self,
+        body_id: BodyId,
+        target_position: impl Into<Point3<Real>>,
+        target_rotation: impl Into<Quaternion<f32>>,
+        delta_time: f32,
+    ) {
+        let target_position = target_position.into().to_fixed_rvec3();
+        unsafe {
+            jolt_sys::JPC_BodyInterface_MoveKinematic(
+                self.0,
+                body_id,
+                target_position.as_ptr(),
+                target_rotation.into().as_ref().as_ptr(),
+                delta_time,
+            );
+        }
+    }
+
+    pub fn friction(&self, body_id: BodyId) -> f32 {
+        unsafe { jolt_sys::JPC_BodyInterface_GetFriction(self.0, body_id) }
+    }
+
+    pub fn set_friction(&self, body_id: BodyId, friction: f32) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_SetFriction(self.0, body_id, friction);
+        }
+    }
+
+    pub fn restitution(&self, body_id: BodyId) -> f32 {
+        unsafe { jolt_sys::JPC_BodyInterface_GetRestitution(self.0, body_id) }
+    }
+
+    pub fn set_restitution(&self, body_id: BodyId, restitution: f32) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_SetRestitution(self.0, body_id, restitution);
+        }
+    }
+
+    pub fn gravity_factor(&self, body_id: BodyId) -> f32 {
+        unsafe { jolt_sys::JPC_BodyInterface_GetGravityFactor(self.0, body_id) }
+    }
+
+    pub fn set_gravity_factor(&self, body_id: BodyId, gravity_factor: f32) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_SetGravityFactor(self.0, body_id, gravity_factor);
+        }
+    }
+
+    pub fn user_data(&self, body_id: BodyId) -> u64 {
+        unsafe { jolt_sys::JPC_BodyInterface_GetUserData(self.0, body_id) }
+    }
+
+    pub fn set_user_data(&self, body_id: BodyId, user_data: u64) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_SetUserData(self.0, body_id, user_data);
+        }
+    }
+
+    /// Inverse inertia tensor in world space.
+    pub fn inverse_inertia(&self, body_id: BodyId) -> ColumnMatrix4<f32> {
+        unsafe {
+            let mut result = [0.; 16];
+            jolt_sys::JPC_BodyInterface_GetInverseInertia(self.0, body_id, result.as_mut_ptr());
+            ColumnMatrix4::from(result)
+        }
+    }
+
+    /// Wakes up all bodies whose bounds overlap the given box.
+    pub fn activate_bodies_in_aabox(
+        &self,
+        min: impl Into<Point3<f32>>,
+        max: impl Into<Point3<f32>>,
+    ) {
+        let min = min.into().to_fixed_vec3();
+        let max = max.into().to_fixed_vec3();
+        unsafe {
+            jolt_sys::JPC_BodyInterface_ActivateBodiesInAABox(
+                self.0,
+                min.as_ptr(),
+                max.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+            );
+        }
+    }
+
+    /// Returns `None` if the body ID is invalid.
+    pub fn transformed_shape(&self, body_id: BodyId) -> Option<TransformedShape> {
+        unsafe {
+            let mut result = MaybeUninit::<jolt_sys::JPC_TransformedShape>::uninit();
+            jolt_sys::JPC_BodyInterface_GetTransformedShape(self.0, body_id, result.as_mut_ptr());
+            TransformedShape::from_raw(result.assume_init())
+        }
+    }
+}
+
+/// Bodies prepared by [`BodyInterface::add_bodies_prepare`], aborts the insertion when dropped.
+pub struct AddBodiesPrepared<'a, 'b> {
+    body_interface: *mut jolt_sys::JPC_BodyInterface,
+    bodies: &'b mut [BodyId],
+    state: *mut jolt_sys::JPC_AddBodiesState,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl AddBodiesPrepared<'_, '_> {
+    pub fn finalize(self, activation: Activation) {
+        let mut this = ManuallyDrop::new(self);
+        unsafe {
+            jolt_sys::JPC_BodyInterface_AddBodiesFinalize(
+                this.body_interface,
+                this.bodies.as_mut_ptr(),
+                batch_len(this.bodies),
+                this.state,
+                activation as _,
+            );
+        }
+    }
+}
+
+impl Drop for AddBodiesPrepared<'_, '_> {
+    fn drop(&mut self) {
+        unsafe {
+            jolt_sys::JPC_BodyInterface_AddBodiesAbort(
+                self.body_interface,
+                self.bodies.as_mut_ptr(),
+                batch_len(self.bodies),
+                self.state,
+            );
+        }
+    }
+}
+
+/// Jolt takes batch sizes as an `int`.
+fn batch_len(bodies: &[BodyId]) -> i32 {
+    i32::try_from(bodies.len()).expect("too many bodies in a single batch")
 }
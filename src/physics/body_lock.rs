@@ -0,0 +1,104 @@
+use crate::{BodyId, BodyMut, BodyRef};
+use std::{marker::PhantomData, mem::MaybeUninit, ops::Deref};
+
+pub struct BodyLockInterface<'a>(*const jolt_sys::JPC_BodyLockInterface, PhantomData<&'a ()>);
+
+impl<'a> From<*const jolt_sys::JPC_BodyLockInterface> for BodyLockInterface<'a> {
+    fn from(ptr: *const jolt_sys::JPC_BodyLockInterface) -> Self {
+        Self(ptr, PhantomData)
+    }
+}
+
+unsafe impl Send for BodyLockInterface<'_> {}
+unsafe impl Sync for BodyLockInterface<'_> {}
+
+impl<'a> BodyLockInterface<'a> {
+    /// Locks a body for reading, returns `None` if the body ID is invalid.
+    pub fn lock_read(&self, body_id: BodyId) -> Option<BodyLockRead<'a>> {
+        let mut lock = unsafe {
+            let mut lock = MaybeUninit::<jolt_sys::JPC_BodyLockRead>::uninit();
+            jolt_sys::JPC_BodyLockInterface_LockRead(self.0, body_id, lock.as_mut_ptr());
+            lock.assume_init()
+        };
+
+        // Jolt may hold the mutex even if the body wasn't found
+        if lock.body.is_null() {
+            unsafe { jolt_sys::JPC_BodyLockInterface_UnlockRead(self.0, &mut lock) };
+            return None;
+        }
+
+        Some(BodyLockRead {
+            body: unsafe { BodyRef::from_raw(lock.body) },
+            lock,
+        })
+    }
+
+    /// Locks a body for writing, returns `None` if the body ID is invalid.
+    pub fn lock_write(&self, body_id: BodyId) -> Option<BodyLockWrite<'a>> {
+        let mut lock = unsafe {
+            let mut lock = MaybeUninit::<jolt_sys::JPC_BodyLockWrite>::uninit();
+            jolt_sys::JPC_BodyLockInterface_LockWrite(self.0, body_id, lock.as_mut_ptr());
+            lock.assume_init()
+        };
+
+        if lock.body.is_null() {
+            unsafe { jolt_sys::JPC_BodyLockInterface_UnlockWrite(self.0, &mut lock) };
+            return None;
+        }
+
+        Some(BodyLockWrite {
+            body: unsafe { BodyRef::from_raw(lock.body) },
+            lock,
+        })
+    }
+}
+
+/// Shared access to a body, the lock is released when the guard is dropped.
+pub struct BodyLockRead<'a> {
+    lock: jolt_sys::JPC_BodyLockRead,
+    body: BodyRef<'a>,
+}
+
+impl<'a> Deref for BodyLockRead<'a> {
+    type Target = BodyRef<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.body
+    }
+}
+
+impl Drop for BodyLockRead<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            jolt_sys::JPC_BodyLockInterface_UnlockRead(self.lock.lock_interface, &mut self.lock);
+        }
+    }
+}
+
+/// Exclusive access to a body, the lock is released when the guard is dropped.
+pub struct BodyLockWrite<'a> {
+    lock: jolt_sys::JPC_BodyLockWrite,
+    body: BodyRef<'a>,
+}
+
+impl BodyLockWrite<'_> {
+    pub fn body_mut(&mut self) -> BodyMut<'_> {
+        unsafe { BodyMut::from_raw(self.lock.body) }
+    }
+}
+
+impl<'a> Deref for BodyLockWrite<'a> {
+    type Target = BodyRef<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.body
+    }
+}
+
+impl Drop for BodyLockWrite<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            jolt_sys::JPC_BodyLockInterface_UnlockWrite(self.lock.lock_interface, &mut self.lock);
+        }
+    }
+}
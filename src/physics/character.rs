@@ -0,0 +1,21 @@
+use crate::impl_enum_try_from;
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GroundState {
+    /// Standing on walkable ground.
+    OnGround = jolt_sys::JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_ON_GROUND as _,
+    /// Touching ground that is too steep to stand on.
+    OnSteepGround =
+        jolt_sys::JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_ON_STEEP_GROUND as _,
+    /// Touching something that isn't supporting the character (e.g. a wall or ceiling).
+    NotSupported = jolt_sys::JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_NOT_SUPPORTED as _,
+    InAir = jolt_sys::JPC_CharacterGroundState_JPC_CHARACTER_GROUND_STATE_IN_AIR as _,
+}
+
+impl_enum_try_from!(GroundState: jolt_sys::JPC_CharacterGroundState {
+    OnGround,
+    OnSteepGround,
+    NotSupported,
+    InAir,
+});
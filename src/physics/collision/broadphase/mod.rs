@@ -1,7 +1,8 @@
 mod vtable;
 
+use crate::CallbackPanic;
 use jolt_sys::{JPC_BroadPhaseLayer, JPC_ObjectLayer};
-use std::ffi::c_void;
+use std::{ffi::c_void, sync::Arc};
 pub use vtable::*;
 
 pub type BroadPhaseLayer = JPC_BroadPhaseLayer;
@@ -9,12 +10,13 @@ pub type ObjectLayer = JPC_ObjectLayer;
 
 #[repr(C)]
 pub struct BroadPhaseLayerInterfaceWrapper {
-    pub(crate) vtable: *const jolt_sys::JPC_BroadPhaseLayerInterfaceVTable,
+    pub(crate) vtable: Box<jolt_sys::JPC_BroadPhaseLayerInterfaceVTable>,
     pub(crate) inner: Box<dyn BroadPhaseLayerInterface>,
+    pub(crate) panic: Arc<CallbackPanic>,
 }
 
 impl BroadPhaseLayerInterfaceWrapper {
-    pub fn new(inner: Box<dyn BroadPhaseLayerInterface>) -> Self {
+    pub(crate) fn new(inner: Box<dyn BroadPhaseLayerInterface>, panic: Arc<CallbackPanic>) -> Self {
         let vtable = Box::new(jolt_sys::JPC_BroadPhaseLayerInterfaceVTable {
             __vtable_header: unsafe { std::mem::zeroed() },
             GetNumBroadPhaseLayers: Some(Self::get_num_broad_phase_layers),
@@ -22,41 +24,66 @@ impl BroadPhaseLayerInterfaceWrapper {
         });
 
         Self {
-            vtable: Box::into_raw(vtable),
+            vtable,
             inner,
+            panic,
         }
     }
 
     unsafe extern "C" fn get_num_broad_phase_layers(wrapper: *const c_void) -> u32 {
-        (*(wrapper as *const Self)).inner.num_broad_phase_layers()
+        let wrapper = &*(wrapper as *const Self);
+        wrapper
+            .panic
+            .catch(0, || wrapper.inner.num_broad_phase_layers())
     }
 
+    // MSVC returns small structs like BroadPhaseLayer through a hidden pointer
+    #[cfg(target_env = "msvc")]
     unsafe extern "C" fn get_broad_phase_layer(
         wrapper: *const c_void,
         layer_out: *mut JPC_BroadPhaseLayer,
         layer: JPC_ObjectLayer,
     ) -> *const JPC_BroadPhaseLayer {
-        *layer_out = (*(wrapper as *const Self)).inner.broad_phase_layer(layer);
+        let wrapper = &*(wrapper as *const Self);
+        *layer_out = wrapper
+            .panic
+            .catch(0, || wrapper.inner.broad_phase_layer(layer));
         layer_out
     }
+
+    #[cfg(not(target_env = "msvc"))]
+    unsafe extern "C" fn get_broad_phase_layer(
+        wrapper: *const c_void,
+        layer: JPC_ObjectLayer,
+    ) -> JPC_BroadPhaseLayer {
+        let wrapper = &*(wrapper as *const Self);
+        wrapper
+            .panic
+            .catch(0, || wrapper.inner.broad_phase_layer(layer))
+    }
 }
 
 #[repr(C)]
 pub struct ObjectVsBroadPhaseLayerFilterWrapper {
-    pub(crate) vtable: *const jolt_sys::JPC_ObjectVsBroadPhaseLayerFilterVTable,
+    pub(crate) vtable: Box<jolt_sys::JPC_ObjectVsBroadPhaseLayerFilterVTable>,
     pub(crate) inner: Box<dyn ObjectVsBroadPhaseLayerFilter>,
+    pub(crate) panic: Arc<CallbackPanic>,
 }
 
 impl ObjectVsBroadPhaseLayerFilterWrapper {
-    pub fn new(inner: Box<dyn ObjectVsBroadPhaseLayerFilter>) -> Self {
+    pub(crate) fn new(
+        inner: Box<dyn ObjectVsBroadPhaseLayerFilter>,
+        panic: Arc<CallbackPanic>,
+    ) -> Self {
         let vtable = Box::new(jolt_sys::JPC_ObjectVsBroadPhaseLayerFilterVTable {
             __vtable_header: unsafe { std::mem::zeroed() },
             ShouldCollide: Some(Self::should_collide),
         });
 
         Self {
-            vtable: Box::into_raw(vtable),
+            vtable,
             inner,
+            panic,
         }
     }
 
@@ -65,28 +92,31 @@ impl ObjectVsBroadPhaseLayerFilterWrapper {
         layer1: JPC_ObjectLayer,
         layer2: JPC_BroadPhaseLayer,
     ) -> bool {
-        (*(wrapper as *const Self))
-            .inner
-            .should_collide(layer1, layer2)
+        let wrapper = &*(wrapper as *const Self);
+        wrapper
+            .panic
+            .catch(false, || wrapper.inner.should_collide(layer1, layer2))
     }
 }
 
 #[repr(C)]
 pub struct ObjectLayerPairFilterWrapper {
-    pub(crate) vtable: *const jolt_sys::JPC_ObjectLayerPairFilterVTable,
+    pub(crate) vtable: Box<jolt_sys::JPC_ObjectLayerPairFilterVTable>,
     pub(crate) inner: Box<dyn ObjectLayerPairFilter>,
+    pub(crate) panic: Arc<CallbackPanic>,
 }
 
 impl ObjectLayerPairFilterWrapper {
-    pub fn new(inner: Box<dyn ObjectLayerPairFilter>) -> Self {
+    pub(crate) fn new(inner: Box<dyn ObjectLayerPairFilter>, panic: Arc<CallbackPanic>) -> Self {
         let vtable = Box::new(jolt_sys::JPC_ObjectLayerPairFilterVTable {
             __vtable_header: unsafe { std::mem::zeroed() },
             ShouldCollide: Some(Self::should_collide),
         });
 
         Self {
-            vtable: Box::into_raw(vtable),
+            vtable,
             inner,
+            panic,
         }
     }
 
@@ -95,8 +125,9 @@ impl ObjectLayerPairFilterWrapper {
         object1: JPC_ObjectLayer,
         object2: JPC_ObjectLayer,
     ) -> bool {
-        (*(wrapper as *const Self))
-            .inner
-            .should_collide(object1, object2)
+        let wrapper = &*(wrapper as *const Self);
+        wrapper
+            .panic
+            .catch(false, || wrapper.inner.should_collide(object1, object2))
     }
 }
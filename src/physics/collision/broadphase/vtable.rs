@@ -1,18 +1,18 @@
 use crate::{BroadPhaseLayer, ObjectLayer};
 
 // JPC_ObjectLayerPairFilterVTable
-pub trait ObjectLayerPairFilter {
+pub trait ObjectLayerPairFilter: Send + Sync {
     fn should_collide(&self, object1: ObjectLayer, object2: ObjectLayer) -> bool;
 }
 
 // JPC_BroadPhaseLayerInterfaceVTable
-pub trait BroadPhaseLayerInterface {
+pub trait BroadPhaseLayerInterface: Send + Sync {
     fn num_broad_phase_layers(&self) -> u32;
     fn broad_phase_layer(&self, layer: ObjectLayer) -> BroadPhaseLayer;
 }
 
 // JPC_ObjectVsBroadPhaseLayerFilterVTable
-pub trait ObjectVsBroadPhaseLayerFilter {
+pub trait ObjectVsBroadPhaseLayerFilter: Send + Sync {
     fn should_collide(&self, layer1: ObjectLayer, layer2: BroadPhaseLayer) -> bool;
 }
 
@@ -0,0 +1,115 @@
+use crate::SharedGroupFilter;
+use std::fmt;
+
+pub type CollisionGroupId = jolt_sys::JPC_CollisionGroupID;
+pub type CollisionSubGroupId = jolt_sys::JPC_CollisionSubGroupID;
+
+/// Decides which bodies can collide based on a group ID, a sub-group ID and a [`GroupFilter`].
+///
+/// Two groups without a filter always collide. Otherwise the filter of the first group that has
+/// one decides, so bodies that should filter each other usually share the same filter.
+///
+/// [`GroupFilter`]: crate::GroupFilter
+// Layout-compatible with JPC_CollisionGroup, and holds a reference to its filter
+#[repr(transparent)]
+pub struct CollisionGroup(jolt_sys::JPC_CollisionGroup);
+
+impl CollisionGroup {
+    pub const INVALID_GROUP: CollisionGroupId = jolt_sys::JPC_COLLISION_GROUP_INVALID_GROUP;
+    pub const INVALID_SUB_GROUP: CollisionSubGroupId =
+        jolt_sys::JPC_COLLISION_GROUP_INVALID_SUB_GROUP;
+
+    pub fn new(
+        filter: Option<SharedGroupFilter>,
+        group_id: CollisionGroupId,
+        sub_group_id: CollisionSubGroupId,
+    ) -> Self {
+        Self(jolt_sys::JPC_CollisionGroup {
+            filter: filter.map_or(std::ptr::null(), SharedGroupFilter::into_raw),
+            group_id,
+            sub_group_id,
+        })
+    }
+
+    /// Reinterprets a group owned by Jolt, without touching the filter's reference count.
+    pub(crate) unsafe fn from_raw_ref<'a>(raw: *const jolt_sys::JPC_CollisionGroup) -> &'a Self {
+        &*(raw as *const Self)
+    }
+
+    /// The returned struct borrows the filter reference from `self`.
+    pub(crate) fn as_raw(&self) -> jolt_sys::JPC_CollisionGroup {
+        self.0
+    }
+
+    pub fn filter(&self) -> Option<SharedGroupFilter> {
+        unsafe { SharedGroupFilter::from_raw_ref(self.0.filter) }
+    }
+
+    pub fn set_filter(&mut self, filter: Option<SharedGroupFilter>) {
+        let previous = std::mem::replace(
+            &mut self.0.filter,
+            filter.map_or(std::ptr::null(), SharedGroupFilter::into_raw),
+        );
+        drop(unsafe { SharedGroupFilter::from_raw(previous) });
+    }
+
+    pub fn group_id(&self) -> CollisionGroupId {
+        self.0.group_id
+    }
+
+    pub fn set_group_id(&mut self, group_id: CollisionGroupId) {
+        self.0.group_id = group_id;
+    }
+
+    pub fn sub_group_id(&self) -> CollisionSubGroupId {
+        self.0.sub_group_id
+    }
+
+    pub fn set_sub_group_id(&mut self, sub_group_id: CollisionSubGroupId) {
+        self.0.sub_group_id = sub_group_id;
+    }
+
+    /// Checks if two groups collide, the same way Jolt does during the simulation.
+    pub fn can_collide(&self, other: &CollisionGroup) -> bool {
+        unsafe {
+            if !self.0.filter.is_null() {
+                jolt_sys::JPC_GroupFilter_CanCollide(self.0.filter, &self.0, &other.0)
+            } else if !other.0.filter.is_null() {
+                jolt_sys::JPC_GroupFilter_CanCollide(other.0.filter, &other.0, &self.0)
+            } else {
+                true
+            }
+        }
+    }
+}
+
+impl Default for CollisionGroup {
+    fn default() -> Self {
+        Self::new(None, Self::INVALID_GROUP, Self::INVALID_SUB_GROUP)
+    }
+}
+
+impl Clone for CollisionGroup {
+    fn clone(&self) -> Self {
+        Self::new(self.filter(), self.0.group_id, self.0.sub_group_id)
+    }
+}
+
+impl Drop for CollisionGroup {
+    fn drop(&mut self) {
+        drop(unsafe { SharedGroupFilter::from_raw(self.0.filter) });
+    }
+}
+
+impl fmt::Debug for CollisionGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CollisionGroup")
+            .field("filter", &self.0.filter)
+            .field("group_id", &self.0.group_id)
+            .field("sub_group_id", &self.0.sub_group_id)
+            .finish()
+    }
+}
+
+unsafe impl Send for CollisionGroup {}
+unsafe impl Sync for CollisionGroup {}
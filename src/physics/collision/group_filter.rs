@@ -0,0 +1,210 @@
+use crate::{abort_on_panic, CollisionGroup, CollisionSubGroupId, JoltRuntime};
+use std::{ffi::c_void, mem::ManuallyDrop, ptr::NonNull};
+
+// JPC_GroupFilterVTable
+/// Decides if two [`CollisionGroup`]s can collide, called from Jolt's worker threads.
+///
+/// A group filter outlives any single physics system, so a panic inside it aborts the process.
+pub trait GroupFilter: Send + Sync {
+    fn can_collide(&self, group1: &CollisionGroup, group2: &CollisionGroup) -> bool;
+}
+
+#[repr(C)]
+struct GroupFilterWrapper {
+    vtable: Box<jolt_sys::JPC_GroupFilterVTable>,
+    inner: Box<dyn GroupFilter>,
+}
+
+impl GroupFilterWrapper {
+    fn new(inner: Box<dyn GroupFilter>) -> Self {
+        let vtable = Box::new(jolt_sys::JPC_GroupFilterVTable {
+            CanCollide: Some(Self::can_collide),
+            Destroy: Some(Self::destroy),
+        });
+
+        Self { vtable, inner }
+    }
+
+    unsafe extern "C" fn can_collide(
+        wrapper: *const c_void,
+        in_group1: *const jolt_sys::JPC_CollisionGroup,
+        in_group2: *const jolt_sys::JPC_CollisionGroup,
+    ) -> bool {
+        let wrapper = &*(wrapper as *const Self);
+        abort_on_panic(|| {
+            wrapper.inner.can_collide(
+                CollisionGroup::from_raw_ref(in_group1),
+                CollisionGroup::from_raw_ref(in_group2),
+            )
+        })
+    }
+
+    unsafe extern "C" fn destroy(wrapper: *mut c_void) {
+        abort_on_panic(|| drop(Box::from_raw(wrapper as *mut Self)));
+    }
+}
+
+/// Reference-counted handle to a group filter, shared with Jolt through [`CollisionGroup`]s.
+pub struct SharedGroupFilter(NonNull<jolt_sys::JPC_GroupFilter>);
+
+impl SharedGroupFilter {
+    pub fn new(_runtime: &JoltRuntime, filter: impl GroupFilter + 'static) -> Self {
+        let wrapper = Box::into_raw(Box::new(GroupFilterWrapper::new(Box::new(filter))));
+        unsafe { Self::from_raw(jolt_sys::JPC_GroupFilter_Create(wrapper as *mut c_void)) }
+            .expect("GroupFilter pointer is NULL")
+    }
+
+    /// Takes over a reference to `filter`, returns `None` if it is NULL.
+    pub(crate) unsafe fn from_raw(filter: *const jolt_sys::JPC_GroupFilter) -> Option<Self> {
+        NonNull::new(filter as *mut jolt_sys::JPC_GroupFilter).map(Self)
+    }
+
+    /// Creates a new reference to `filter`, returns `None` if it is NULL.
+    pub(crate) unsafe fn from_raw_ref(filter: *const jolt_sys::JPC_GroupFilter) -> Option<Self> {
+        let filter = Self::from_raw(filter)?;
+        jolt_sys::JPC_GroupFilter_AddRef(filter.0.as_ptr());
+        Some(filter)
+    }
+
+    /// Hands the reference over to the caller.
+    pub(crate) fn into_raw(self) -> *const jolt_sys::JPC_GroupFilter {
+        ManuallyDrop::new(self).0.as_ptr()
+    }
+
+    pub fn can_collide(&self, group1: &CollisionGroup, group2: &CollisionGroup) -> bool {
+        unsafe {
+            jolt_sys::JPC_GroupFilter_CanCollide(
+                self.0.as_ptr(),
+                &group1.as_raw(),
+                &group2.as_raw(),
+            )
+        }
+    }
+}
+
+impl Clone for SharedGroupFilter {
+    fn clone(&self) -> Self {
+        unsafe {
+            jolt_sys::JPC_GroupFilter_AddRef(self.0.as_ptr());
+        }
+        Self(self.0)
+    }
+}
+
+impl Drop for SharedGroupFilter {
+    fn drop(&mut self) {
+        unsafe {
+            jolt_sys::JPC_GroupFilter_Release(self.0.as_ptr());
+        }
+    }
+}
+
+unsafe impl Send for SharedGroupFilter {}
+unsafe impl Sync for SharedGroupFilter {}
+
+/// Jolt's built-in filter that keeps a table of which sub-groups collide with each other.
+///
+/// Groups with different group IDs always collide, and sub-groups never collide with themselves.
+/// Every other pair collides until [`disable_collision`](Self::disable_collision) is called, e.g.
+/// for adjacent ragdoll limbs. The table can't be changed once it's shared with Jolt.
+pub struct GroupFilterTable {
+    filter: SharedGroupFilter,
+    num_sub_groups: u32,
+}
+
+impl GroupFilterTable {
+    pub fn new(_runtime: &JoltRuntime, num_sub_groups: u32) -> Self {
+        let filter = unsafe {
+            SharedGroupFilter::from_raw(jolt_sys::JPC_GroupFilterTable_Create(num_sub_groups))
+        }
+        .expect("GroupFilterTable pointer is NULL");
+
+        Self {
+            filter,
+            num_sub_groups,
+        }
+    }
+
+    pub fn num_sub_groups(&self) -> u32 {
+        self.num_sub_groups
+    }
+
+    /// # Panics
+    /// If either sub-group is out of range, or both are the same.
+    pub fn disable_collision(
+        &mut self,
+        sub_group1: CollisionSubGroupId,
+        sub_group2: CollisionSubGroupId,
+    ) {
+        self.check_pair(sub_group1, sub_group2);
+        unsafe {
+            jolt_sys::JPC_GroupFilterTable_DisableCollision(
+                self.filter.0.as_ptr(),
+                sub_group1,
+                sub_group2,
+            );
+        }
+    }
+
+    /// # Panics
+    /// If either sub-group is out of range, or both are the same.
+    pub fn enable_collision(
+        &mut self,
+        sub_group1: CollisionSubGroupId,
+        sub_group2: CollisionSubGroupId,
+    ) {
+        self.check_pair(sub_group1, sub_group2);
+        unsafe {
+            jolt_sys::JPC_GroupFilterTable_EnableCollision(
+                self.filter.0.as_ptr(),
+                sub_group1,
+                sub_group2,
+            );
+        }
+    }
+
+    /// Always `false` for a sub-group and itself.
+    ///
+    /// # Panics
+    /// If either sub-group is out of range.
+    pub fn is_collision_enabled(
+        &self,
+        sub_group1: CollisionSubGroupId,
+        sub_group2: CollisionSubGroupId,
+    ) -> bool {
+        self.check_range(sub_group1, sub_group2);
+        if sub_group1 == sub_group2 {
+            return false;
+        }
+        unsafe {
+            jolt_sys::JPC_GroupFilterTable_IsCollisionEnabled(
+                self.filter.0.as_ptr(),
+                sub_group1,
+                sub_group2,
+            )
+        }
+    }
+
+    // Jolt only asserts on these, and indexes out of bounds otherwise
+    fn check_range(&self, sub_group1: CollisionSubGroupId, sub_group2: CollisionSubGroupId) {
+        assert!(
+            sub_group1 < self.num_sub_groups && sub_group2 < self.num_sub_groups,
+            "Sub-group out of range ({sub_group1}, {sub_group2} >= {})",
+            self.num_sub_groups
+        );
+    }
+
+    fn check_pair(&self, sub_group1: CollisionSubGroupId, sub_group2: CollisionSubGroupId) {
+        self.check_range(sub_group1, sub_group2);
+        assert_ne!(
+            sub_group1, sub_group2,
+            "A sub-group never collides with itself"
+        );
+    }
+}
+
+impl From<GroupFilterTable> for SharedGroupFilter {
+    fn from(table: GroupFilterTable) -> Self {
+        table.filter
+    }
+}
@@ -1,8 +1,16 @@
 pub mod broadphase;
+pub mod collision_group;
+pub mod group_filter;
+pub mod physics_material;
 pub mod shape;
+pub mod transformed_shape;
 
 pub use broadphase::*;
+pub use collision_group::*;
+pub use group_filter::*;
+pub use physics_material::*;
 pub use shape::*;
+pub use transformed_shape::*;
 
 pub type CollideShapeResult = jolt_sys::JPC_CollideShapeResult;
 pub type ContactManifold = jolt_sys::JPC_ContactManifold;
@@ -0,0 +1,91 @@
+use crate::JoltRuntime;
+use std::{
+    borrow::Cow,
+    ffi::{CStr, CString},
+    ptr::NonNull,
+};
+
+/// Reference-counted surface material, for telling apart the parts of a shape (e.g. terrain cells).
+///
+/// Materials compare equal if they're the same Jolt object.
+pub struct PhysicsMaterial(NonNull<jolt_sys::JPC_PhysicsMaterial>);
+
+impl PhysicsMaterial {
+    /// # Panics
+    /// If `debug_name` contains a NUL byte.
+    pub fn new(_runtime: &JoltRuntime, debug_name: &str, debug_color: [u8; 4]) -> Self {
+        let debug_name = CString::new(debug_name).expect("Material name contains a NUL byte");
+        unsafe {
+            let material = jolt_sys::JPC_PhysicsMaterial_Create(
+                debug_name.as_ptr(),
+                u32::from_le_bytes(debug_color),
+            );
+            Self(NonNull::new(material).expect("PhysicsMaterial pointer is NULL"))
+        }
+    }
+
+    /// Creates a new reference to `material`, returns `None` if it is NULL.
+    pub(crate) unsafe fn from_raw_ref(
+        material: *const jolt_sys::JPC_PhysicsMaterial,
+    ) -> Option<Self> {
+        let material = NonNull::new(material as *mut jolt_sys::JPC_PhysicsMaterial)?;
+        jolt_sys::JPC_PhysicsMaterial_AddRef(material.as_ptr());
+        Some(Self(material))
+    }
+
+    pub(crate) fn as_raw(&self) -> *const jolt_sys::JPC_PhysicsMaterial {
+        self.0.as_ptr()
+    }
+
+    pub fn debug_name(&self) -> Cow<'_, str> {
+        unsafe { CStr::from_ptr(jolt_sys::JPC_PhysicsMaterial_GetDebugName(self.as_raw())) }
+            .to_string_lossy()
+    }
+
+    /// RGBA color used by the debug renderer.
+    pub fn debug_color(&self) -> [u8; 4] {
+        unsafe { jolt_sys::JPC_PhysicsMaterial_GetDebugColor(self.as_raw()) }.to_le_bytes()
+    }
+}
+
+impl Clone for PhysicsMaterial {
+    fn clone(&self) -> Self {
+        unsafe {
+            jolt_sys::JPC_PhysicsMaterial_AddRef(self.as_raw());
+        }
+        Self(self.0)
+    }
+}
+
+impl Drop for PhysicsMaterial {
+    fn drop(&mut self) {
+        unsafe {
+            jolt_sys::JPC_PhysicsMaterial_Release(self.as_raw());
+        }
+    }
+}
+
+impl PartialEq for PhysicsMaterial {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for PhysicsMaterial {}
+
+impl std::hash::Hash for PhysicsMaterial {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl std::fmt::Debug for PhysicsMaterial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhysicsMaterial")
+            .field("debug_name", &self.debug_name())
+            .finish()
+    }
+}
+
+unsafe impl Send for PhysicsMaterial {}
+unsafe impl Sync for PhysicsMaterial {}
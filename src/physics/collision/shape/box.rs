@@ -1,11 +1,11 @@
-use crate::{shape::shape_settings::ShapeSettings, HasShapeSettings};
+use crate::{shape::shape_settings::ShapeSettings, HasShapeSettings, JoltRuntime};
 use mint::Vector3;
 
 #[repr(transparent)]
 pub struct BoxShapeSettings(ShapeSettings);
 
 impl BoxShapeSettings {
-    pub fn new<V>(half_extents: V) -> Self
+    pub fn new<V>(_runtime: &JoltRuntime, half_extents: V) -> Self
     where
         V: Into<Vector3<f32>>,
     {
@@ -1,10 +1,10 @@
-use crate::{shape::shape_settings::ShapeSettings, HasShapeSettings};
+use crate::{shape::shape_settings::ShapeSettings, HasShapeSettings, JoltRuntime};
 
 #[repr(transparent)]
 pub struct CapsuleShapeSettings(ShapeSettings);
 
 impl CapsuleShapeSettings {
-    pub fn new(half_height: f32, radius: f32) -> Self {
+    pub fn new(_runtime: &JoltRuntime, half_height: f32, radius: f32) -> Self {
         unsafe {
             Self(ShapeSettings::from_raw(
                 jolt_sys::JPC_CapsuleShapeSettings_Create(half_height, radius) as _,
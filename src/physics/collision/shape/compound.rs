@@ -1,12 +1,12 @@
 use mint::{Quaternion, Vector3};
 
-use crate::{HasShapeSettings, ShapeSettings};
+use crate::{HasShapeSettings, JoltRuntime, ShapeSettings};
 
 #[repr(transparent)]
 pub struct StaticCompoundShapeSettings(ShapeSettings);
 
 impl StaticCompoundShapeSettings {
-    pub fn new() -> Self {
+    pub fn new(_runtime: &JoltRuntime) -> Self {
         unsafe {
             Self(ShapeSettings::from_raw(
                 jolt_sys::JPC_StaticCompoundShapeSettings_Create() as _,
@@ -2,13 +2,13 @@ use std::ffi::c_void;
 
 use mint::Vector3;
 
-use crate::{HasShapeSettings, ShapeSettings};
+use crate::{HasShapeSettings, JoltRuntime, ShapeSettings};
 
 #[repr(transparent)]
 pub struct ConvexHullShapeSettings(ShapeSettings);
 
 impl ConvexHullShapeSettings {
-    pub fn new(vertices: &[Vector3<f32>]) -> Self {
+    pub fn new(_runtime: &JoltRuntime, vertices: &[Vector3<f32>]) -> Self {
         unsafe {
             Self(ShapeSettings::from_raw(
                 jolt_sys::JPC_ConvexHullShapeSettings_Create(
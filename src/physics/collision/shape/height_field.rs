@@ -0,0 +1,376 @@
+use std::ops::Deref;
+
+use crate::{
+    Error, HasShapeSettings, JoltRuntime, PhysicsMaterial, Shape, ShapeSettings, TempAllocator,
+};
+use mint::{Point3, Vector3};
+
+/// Terrain made of a square grid of height samples.
+///
+/// Sample `(x, z)` is stored at `samples[z * sample_count + x]` and ends up at
+/// `offset + scale * (x, height, z)` in the shape's local space.
+pub struct HeightFieldShapeSettings {
+    settings: ShapeSettings,
+    sample_count: u32,
+}
+
+impl HeightFieldShapeSettings {
+    /// Height value of a sample that has no collision, use it to cut holes into the terrain.
+    // Matches JPH::HeightFieldShapeConstants::cNoCollisionValue
+    pub const NO_COLLISION: f32 = f32::MAX;
+
+    /// Creates settings from a square grid of at least 2x2 samples.
+    pub fn new(_runtime: &JoltRuntime, samples: &[f32]) -> crate::Result<Self> {
+        let sample_count = (samples.len() as f64).sqrt() as usize;
+        if sample_count * sample_count != samples.len() {
+            return Err(Error::InvalidHeightField(format!(
+                "{} samples don't form a square grid",
+                samples.len()
+            )));
+        }
+        if sample_count < 2 {
+            return Err(Error::InvalidHeightField(
+                "at least 2x2 samples are needed".to_owned(),
+            ));
+        }
+        let sample_count = u32::try_from(sample_count)
+            .map_err(|_| Error::InvalidHeightField("too many samples".to_owned()))?;
+
+        let settings = unsafe {
+            ShapeSettings::from_raw(jolt_sys::JPC_HeightFieldShapeSettings_Create(
+                samples.as_ptr(),
+                sample_count,
+            ) as _)
+        };
+
+        Ok(Self {
+            settings,
+            sample_count,
+        })
+    }
+
+    fn as_raw(&self) -> *mut jolt_sys::JPC_HeightFieldShapeSettings {
+        self.settings.as_raw() as _
+    }
+
+    /// Number of samples along each side of the grid.
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Assigns a material to each cell between four samples.
+    ///
+    /// `material_indices` index into `materials`, one per cell in the same order as the samples,
+    /// so there are `(sample_count - 1)²` of them.
+    pub fn set_materials(
+        &mut self,
+        materials: &[PhysicsMaterial],
+        material_indices: &[u8],
+    ) -> crate::Result<()> {
+        let num_cells = (self.sample_count as usize - 1).pow(2);
+        if material_indices.len() != num_cells {
+            return Err(Error::InvalidHeightField(format!(
+                "expected {num_cells} material indices, got {}",
+                material_indices.len()
+            )));
+        }
+        if let Some(index) = material_indices
+            .iter()
+            .find(|&&index| index as usize >= materials.len())
+        {
+            return Err(Error::InvalidHeightField(format!(
+                "material index {index} is out of range for {} materials",
+                materials.len()
+            )));
+        }
+
+        let materials: Vec<_> = materials.iter().map(PhysicsMaterial::as_raw).collect();
+        unsafe {
+            jolt_sys::JPC_HeightFieldShapeSettings_SetMaterials(
+                self.as_raw(),
+                materials.as_ptr(),
+                materials.len() as u32,
+            );
+            jolt_sys::JPC_HeightFieldShapeSettings_SetMaterialIndices(
+                self.as_raw(),
+                material_indices.as_ptr(),
+                material_indices.len() as u32,
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn offset(&self) -> Vector3<f32> {
+        unsafe {
+            let mut offset = [0.0; 3];
+            jolt_sys::JPC_HeightFieldShapeSettings_GetOffset(self.as_raw(), offset.as_mut_ptr());
+            offset.into()
+        }
+    }
+
+    pub fn set_offset(&mut self, offset: impl Into<Vector3<f32>>) {
+        unsafe {
+            jolt_sys::JPC_HeightFieldShapeSettings_SetOffset(
+                self.as_raw(),
+                offset.into().as_ref().as_ptr(),
+            );
+        }
+    }
+
+    pub fn scale(&self) -> Vector3<f32> {
+        unsafe {
+            let mut scale = [0.0; 3];
+            jolt_sys::JPC_HeightFieldShapeSettings_GetScale(self.as_raw(), scale.as_mut_ptr());
+            scale.into()
+        }
+    }
+
+    pub fn set_scale(&mut self, scale: impl Into<Vector3<f32>>) {
+        unsafe {
+            jolt_sys::JPC_HeightFieldShapeSettings_SetScale(
+                self.as_raw(),
+                scale.into().as_ref().as_ptr(),
+            );
+        }
+    }
+
+    pub fn block_size(&self) -> u32 {
+        unsafe { jolt_sys::JPC_HeightFieldShapeSettings_GetBlockSize(self.as_raw()) }
+    }
+
+    /// Size of the blocks the grid is split into for culling, Jolt accepts 2 to 8.
+    pub fn set_block_size(&mut self, block_size: u32) {
+        unsafe { jolt_sys::JPC_HeightFieldShapeSettings_SetBlockSize(self.as_raw(), block_size) }
+    }
+
+    pub fn bits_per_sample(&self) -> u32 {
+        unsafe { jolt_sys::JPC_HeightFieldShapeSettings_GetBitsPerSample(self.as_raw()) }
+    }
+
+    /// Precision samples are compressed to, Jolt accepts 1 to 8.
+    pub fn set_bits_per_sample(&mut self, bits_per_sample: u32) {
+        unsafe {
+            jolt_sys::JPC_HeightFieldShapeSettings_SetBitsPerSample(self.as_raw(), bits_per_sample)
+        }
+    }
+
+    pub fn min_height_value(&self) -> f32 {
+        unsafe { jolt_sys::JPC_HeightFieldShapeSettings_GetMinHeightValue(self.as_raw()) }
+    }
+
+    /// Lowers the bottom of the height range the shape can represent, so it can be edited to
+    /// heights below the lowest sample later. Samples below it extend the range instead.
+    pub fn set_min_height_value(&mut self, min_height_value: f32) {
+        unsafe {
+            jolt_sys::JPC_HeightFieldShapeSettings_SetMinHeightValue(
+                self.as_raw(),
+                min_height_value,
+            )
+        }
+    }
+
+    pub fn max_height_value(&self) -> f32 {
+        unsafe { jolt_sys::JPC_HeightFieldShapeSettings_GetMaxHeightValue(self.as_raw()) }
+    }
+
+    /// Raises the top of the height range the shape can represent, so it can be edited to heights
+    /// above the highest sample later. Samples above it extend the range instead.
+    pub fn set_max_height_value(&mut self, max_height_value: f32) {
+        unsafe {
+            jolt_sys::JPC_HeightFieldShapeSettings_SetMaxHeightValue(
+                self.as_raw(),
+                max_height_value,
+            )
+        }
+    }
+}
+
+impl HasShapeSettings for HeightFieldShapeSettings {
+    fn as_shape_settings(&self) -> &ShapeSettings {
+        &self.settings
+    }
+}
+
+impl AsRef<ShapeSettings> for HeightFieldShapeSettings {
+    fn as_ref(&self) -> &ShapeSettings {
+        self.as_shape_settings()
+    }
+}
+
+/// A created height field, see [`Shape::as_height_field`].
+///
+/// The sample count can be larger than the one in the settings, Jolt rounds it up to a multiple of
+/// the block size.
+#[repr(transparent)]
+pub struct HeightFieldShape(Shape);
+
+impl HeightFieldShape {
+    /// # Safety
+    /// `shape` must be a height field.
+    pub(crate) unsafe fn from_shape_ref(shape: &Shape) -> &Self {
+        &*(shape as *const Shape as *const Self)
+    }
+
+    pub fn as_shape(&self) -> &Shape {
+        &self.0
+    }
+
+    /// Number of samples along each side of the grid.
+    pub fn sample_count(&self) -> u32 {
+        unsafe { jolt_sys::JPC_HeightFieldShape_GetSampleCount(self.0.as_raw()) }
+    }
+
+    pub fn block_size(&self) -> u32 {
+        unsafe { jolt_sys::JPC_HeightFieldShape_GetBlockSize(self.0.as_raw()) }
+    }
+
+    /// Lowest height the shape can represent, see
+    /// [`HeightFieldShapeSettings::set_min_height_value`].
+    pub fn min_height_value(&self) -> f32 {
+        unsafe { jolt_sys::JPC_HeightFieldShape_GetMinHeightValue(self.0.as_raw()) }
+    }
+
+    /// Highest height the shape can represent, see
+    /// [`HeightFieldShapeSettings::set_max_height_value`].
+    pub fn max_height_value(&self) -> f32 {
+        unsafe { jolt_sys::JPC_HeightFieldShape_GetMaxHeightValue(self.0.as_raw()) }
+    }
+
+    /// Local space position of sample `(x, z)`.
+    pub fn position(&self, x: u32, z: u32) -> Point3<f32> {
+        assert!(
+            x < self.sample_count() && z < self.sample_count(),
+            "sample ({x}, {z}) is outside of the height field"
+        );
+        let mut position = [0.0; 3];
+        unsafe {
+            jolt_sys::JPC_HeightFieldShape_GetPosition(
+                self.0.as_raw(),
+                x,
+                z,
+                position.as_mut_ptr(),
+            );
+        }
+        position.into()
+    }
+
+    /// Reads the `size_x` by `size_z` samples starting at `(x, z)` into `heights`, laid out like
+    /// the samples of [`HeightFieldShapeSettings::new`] but with rows of `size_x`.
+    ///
+    /// `x`, `z`, `size_x` and `size_z` must be multiples of the block size, except for sizes that
+    /// reach the edge of the grid. Samples without collision are read back as
+    /// [`HeightFieldShapeSettings::NO_COLLISION`].
+    pub fn get_heights(
+        &self,
+        x: u32,
+        z: u32,
+        size_x: u32,
+        size_z: u32,
+        heights: &mut [f32],
+    ) -> crate::Result<()> {
+        self.check_region(x, z, size_x, size_z, heights.len())?;
+        unsafe {
+            jolt_sys::JPC_HeightFieldShape_GetHeights(
+                self.0.as_raw(),
+                x,
+                z,
+                size_x,
+                size_z,
+                heights.as_mut_ptr(),
+                size_x as isize,
+            );
+        }
+        Ok(())
+    }
+
+    /// Overwrites the `size_x` by `size_z` samples starting at `(x, z)`, with the same layout and
+    /// restrictions as [`HeightFieldShape::get_heights`].
+    ///
+    /// Heights are clamped to [`HeightFieldShape::min_height_value`] and
+    /// [`HeightFieldShape::max_height_value`], which are fixed when the shape is created.
+    ///
+    /// This doesn't wake bodies resting on the changed region, use
+    /// [`PhysicsSystem::set_height_field_heights`](crate::PhysicsSystem::set_height_field_heights)
+    /// for a height field that's part of a simulation.
+    ///
+    /// # Safety
+    /// The shape is modified in place, no other thread may use it (e.g. by stepping or querying a
+    /// physics system containing it) while this runs.
+    pub unsafe fn set_heights(
+        &self,
+        x: u32,
+        z: u32,
+        size_x: u32,
+        size_z: u32,
+        heights: &[f32],
+        temp_allocator: &mut TempAllocator,
+    ) -> crate::Result<()> {
+        self.check_region(x, z, size_x, size_z, heights.len())?;
+        jolt_sys::JPC_HeightFieldShape_SetHeights(
+            self.0.as_raw(),
+            x,
+            z,
+            size_x,
+            size_z,
+            heights.as_ptr(),
+            size_x as isize,
+            temp_allocator.as_ptr(),
+        );
+        Ok(())
+    }
+
+    fn check_region(
+        &self,
+        x: u32,
+        z: u32,
+        size_x: u32,
+        size_z: u32,
+        num_heights: usize,
+    ) -> crate::Result<()> {
+        let sample_count = self.sample_count();
+        let block_size = self.block_size();
+        if !x.is_multiple_of(block_size) || !z.is_multiple_of(block_size) {
+            return Err(Error::InvalidHeightField(format!(
+                "region start ({x}, {z}) is not a multiple of the block size {block_size}"
+            )));
+        }
+        let reaches_edge =
+            |start: u32, size: u32| start as u64 + size as u64 == sample_count as u64;
+        if !(size_x.is_multiple_of(block_size) || reaches_edge(x, size_x))
+            || !(size_z.is_multiple_of(block_size) || reaches_edge(z, size_z))
+        {
+            return Err(Error::InvalidHeightField(format!(
+                "region size {size_x}x{size_z} is not a multiple of the block size {block_size}"
+            )));
+        }
+        if x as u64 + size_x as u64 > sample_count as u64
+            || z as u64 + size_z as u64 > sample_count as u64
+        {
+            return Err(Error::InvalidHeightField(format!(
+                "region of {size_x}x{size_z} samples at ({x}, {z}) doesn't fit {sample_count}x{sample_count} samples"
+            )));
+        }
+        let expected = size_x as usize * size_z as usize;
+        if num_heights != expected {
+            return Err(Error::InvalidHeightField(format!(
+                "expected {expected} heights, got {num_heights}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Deref for HeightFieldShape {
+    type Target = Shape;
+
+    fn deref(&self) -> &Shape {
+        &self.0
+    }
+}
+
+impl AsRef<Shape> for HeightFieldShape {
+    fn as_ref(&self) -> &Shape {
+        &self.0
+    }
+}
@@ -3,34 +3,50 @@ mod capsule;
 mod compound;
 mod convex_hull;
 mod cylinder;
+mod height_field;
 mod mesh;
+mod offset_center_of_mass;
 mod rotated_translated;
 mod scaled;
 mod shape_settings;
 mod sphere;
+mod tapered_capsule;
+mod triangle;
 
 use std::ptr::NonNull;
 
+use crate::{impl_enum_try_from, JoltRuntime, PhysicsMaterial};
+
 pub use capsule::*;
 pub use compound::*;
 pub use convex_hull::*;
 pub use cylinder::*;
+pub use height_field::*;
 use jolt_sys::JPC_MassProperties;
 pub use mesh::*;
-use mint::Vector3;
+use mint::{Point3, Vector3};
+pub use offset_center_of_mass::*;
 pub use r#box::*;
 pub use rotated_translated::*;
 pub use scaled::*;
 pub use shape_settings::*;
 pub use sphere::*;
+pub use tapered_capsule::*;
+pub use triangle::*;
 
+pub type SubShapeID = jolt_sys::JPC_SubShapeID;
 pub type SubShapeIDPair = jolt_sys::JPC_SubShapeIDPair;
 
-pub struct Shape(NonNull<jolt_sys::JPC_Shape>);
+// Shapes can outlive the `JoltRuntime` they were created with, so each one keeps it alive.
+pub struct Shape(NonNull<jolt_sys::JPC_Shape>, JoltRuntime);
 
 impl Shape {
+    pub(crate) fn new(inner: *mut jolt_sys::JPC_Shape, runtime: JoltRuntime) -> Self {
+        Self(NonNull::new(inner).expect("Shape pointer is NULL"), runtime)
+    }
+
     pub(crate) fn from_raw(inner: *mut jolt_sys::JPC_Shape) -> Self {
-        Self(NonNull::new(inner).expect("Shape pointer is NULL"))
+        Self::new(inner, JoltRuntime::acquire())
     }
 
     pub(crate) fn as_raw(&self) -> *mut jolt_sys::JPC_Shape {
@@ -53,19 +69,48 @@ impl Shape {
         properties
     }
 
-    pub fn shape_type(&self) -> ShapeType {
+    pub fn shape_type(&self) -> crate::Result<ShapeType> {
+        unsafe { jolt_sys::JPC_Shape_GetType(self.0.as_ptr()) }.try_into()
+    }
+
+    pub fn shape_subtype(&self) -> crate::Result<ShapeSubType> {
+        unsafe { jolt_sys::JPC_Shape_GetSubType(self.0.as_ptr()) }.try_into()
+    }
+
+    pub fn user_data(&self) -> u64 {
+        unsafe { jolt_sys::JPC_Shape_GetUserData(self.0.as_ptr()) }
+    }
+
+    /// Sets the user data of this shape, which is shared with all of its clones.
+    ///
+    /// # Safety
+    /// The shape is modified in place, no other thread may use it (e.g. by stepping or querying a
+    /// physics system containing it) while this runs.
+    pub unsafe fn set_user_data(&self, user_data: u64) {
+        jolt_sys::JPC_Shape_SetUserData(self.0.as_ptr(), user_data);
+    }
+
+    /// Bounding box of the shape in its local space, as `(min, max)`.
+    pub fn local_bounds(&self) -> (Point3<f32>, Point3<f32>) {
+        let mut min = [0.0; 3];
+        let mut max = [0.0; 3];
         unsafe {
-            std::mem::transmute::<jolt_sys::JPC_ShapeType, ShapeType>(jolt_sys::JPC_Shape_GetType(
-                self.0.as_ptr(),
-            ))
+            jolt_sys::JPC_Shape_GetLocalBounds(self.0.as_ptr(), min.as_mut_ptr(), max.as_mut_ptr());
         }
+        (min.into(), max.into())
+    }
+
+    pub fn as_height_field(&self) -> Option<&HeightFieldShape> {
+        matches!(self.shape_subtype(), Ok(ShapeSubType::HeightField))
+            .then(|| unsafe { HeightFieldShape::from_shape_ref(self) })
     }
 
-    pub fn shape_subtype(&self) -> ShapeSubType {
+    pub fn material(&self, sub_shape_id: SubShapeID) -> Option<PhysicsMaterial> {
         unsafe {
-            std::mem::transmute::<jolt_sys::JPC_ShapeSubType, ShapeSubType>(
-                jolt_sys::JPC_Shape_GetSubType(self.0.as_ptr()),
-            )
+            PhysicsMaterial::from_raw_ref(jolt_sys::JPC_Shape_GetMaterial(
+                self.0.as_ptr(),
+                sub_shape_id,
+            ))
         }
     }
 }
@@ -75,7 +120,7 @@ impl Clone for Shape {
         unsafe {
             jolt_sys::JPC_Shape_AddRef(self.0.as_ptr());
         }
-        Self(self.0)
+        Self(self.0, self.1.clone())
     }
 }
 
@@ -98,6 +143,7 @@ pub enum ShapeType {
     Decorated = jolt_sys::JPC_EShapeType_JPC_SHAPE_TYPE_DECORATED as u8,
     Mesh = jolt_sys::JPC_EShapeType_JPC_SHAPE_TYPE_MESH as u8,
     HeightField = jolt_sys::JPC_EShapeType_JPC_SHAPE_TYPE_HEIGHT_FIELD as u8,
+    SoftBody = jolt_sys::JPC_EShapeType_JPC_SHAPE_TYPE_SOFT_BODY as u8,
     User1 = jolt_sys::JPC_EShapeType_JPC_SHAPE_TYPE_USER1 as u8,
     User2 = jolt_sys::JPC_EShapeType_JPC_SHAPE_TYPE_USER2 as u8,
     User3 = jolt_sys::JPC_EShapeType_JPC_SHAPE_TYPE_USER3 as u8,
@@ -121,6 +167,7 @@ pub enum ShapeSubType {
     OffsetCenterOfMass = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_OFFSET_CENTER_OF_MASS as u8,
     Mesh = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_MESH as u8,
     HeightField = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_HEIGHT_FIELD as u8,
+    SoftBody = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_SOFT_BODY as u8,
     User1 = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER1 as u8,
     User2 = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER2 as u8,
     User3 = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER3 as u8,
@@ -138,3 +185,14 @@ pub enum ShapeSubType {
     UserConvex7 = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX7 as u8,
     UserConvex8 = jolt_sys::JPC_EShapeSubType_JPC_SHAPE_SUB_TYPE_USER_CONVEX8 as u8,
 }
+
+impl_enum_try_from!(ShapeType: jolt_sys::JPC_ShapeType {
+    Convex, Compound, Decorated, Mesh, HeightField, SoftBody, User1, User2, User3, User4,
+});
+
+impl_enum_try_from!(ShapeSubType: jolt_sys::JPC_ShapeSubType {
+    Sphere, Box, Triangle, Capsule, TaperedCapsule, Cylinder, ConvexHull, StaticCompound,
+    MutableCompound, RotatedTranslated, Scaled, OffsetCenterOfMass, Mesh, HeightField, SoftBody,
+    User1, User2, User3, User4, User5, User6, User7, User8, UserConvex1, UserConvex2,
+    UserConvex3, UserConvex4, UserConvex5, UserConvex6, UserConvex7, UserConvex8,
+});
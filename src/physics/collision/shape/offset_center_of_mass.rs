@@ -0,0 +1,61 @@
+use mint::Vector3;
+
+use crate::{HasShapeSettings, JoltRuntime, ShapeSettings};
+
+/// Moves the center of mass of the inner shape by `offset` without moving the shape itself, e.g.
+/// to lower the center of mass of a vehicle.
+#[repr(transparent)]
+pub struct OffsetCenterOfMassShapeSettings(ShapeSettings);
+
+impl OffsetCenterOfMassShapeSettings {
+    pub fn new(
+        _runtime: &JoltRuntime,
+        shape_settings: &ShapeSettings,
+        offset: impl Into<Vector3<f32>>,
+    ) -> Self {
+        unsafe {
+            Self(ShapeSettings::from_raw(
+                jolt_sys::JPC_OffsetCenterOfMassShapeSettings_Create(
+                    shape_settings.as_raw(),
+                    offset.into().as_ref().as_ptr(),
+                ) as _,
+            ))
+        }
+    }
+
+    fn as_raw(&self) -> *mut jolt_sys::JPC_DecoratedShapeSettings {
+        self.0.as_raw() as _
+    }
+
+    pub fn offset(&self) -> Vector3<f32> {
+        let mut offset = [0.0; 3];
+        unsafe {
+            jolt_sys::JPC_OffsetCenterOfMassShapeSettings_GetOffset(
+                self.as_raw(),
+                offset.as_mut_ptr(),
+            );
+        }
+        offset.into()
+    }
+
+    pub fn set_offset(&mut self, offset: impl Into<Vector3<f32>>) {
+        unsafe {
+            jolt_sys::JPC_OffsetCenterOfMassShapeSettings_SetOffset(
+                self.as_raw(),
+                offset.into().as_ref().as_ptr(),
+            );
+        }
+    }
+}
+
+impl HasShapeSettings for OffsetCenterOfMassShapeSettings {
+    fn as_shape_settings(&self) -> &ShapeSettings {
+        &self.0
+    }
+}
+
+impl AsRef<ShapeSettings> for OffsetCenterOfMassShapeSettings {
+    fn as_ref(&self) -> &ShapeSettings {
+        self.as_shape_settings()
+    }
+}
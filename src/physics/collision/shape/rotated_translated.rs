@@ -1,12 +1,13 @@
 use mint::{Quaternion, Vector3};
 
-use crate::{HasShapeSettings, ShapeSettings};
+use crate::{HasShapeSettings, JoltRuntime, ShapeSettings};
 
 #[repr(transparent)]
 pub struct RotatedTranslatedShapeSettings(ShapeSettings);
 
 impl RotatedTranslatedShapeSettings {
     pub fn new(
+        _runtime: &JoltRuntime,
         shape_settings: &ShapeSettings,
         rotation: impl Into<Quaternion<f32>>,
         translation: impl Into<Vector3<f32>>,
@@ -1,12 +1,16 @@
 use mint::Vector3;
 
-use crate::{HasShapeSettings, ShapeSettings};
+use crate::{HasShapeSettings, JoltRuntime, ShapeSettings};
 
 #[repr(transparent)]
 pub struct ScaledShapeSettings(ShapeSettings);
 
 impl ScaledShapeSettings {
-    pub fn new(shape_settings: &ShapeSettings, scale: impl Into<Vector3<f32>>) -> Self {
+    pub fn new(
+        _runtime: &JoltRuntime,
+        shape_settings: &ShapeSettings,
+        scale: impl Into<Vector3<f32>>,
+    ) -> Self {
         unsafe {
             Self(ShapeSettings::from_raw(
                 jolt_sys::JPC_ScaledShapeSettings_Create(
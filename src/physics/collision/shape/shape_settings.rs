@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::Shape;
+use crate::{Error, JoltRuntime, Shape};
 use jolt_sys::JPC_ShapeSettings;
 
 pub struct ShapeSettings(*mut JPC_ShapeSettings);
@@ -15,7 +15,18 @@ impl ShapeSettings {
         Self(raw)
     }
 
-    pub fn create_shape(&self) -> Result<Shape, String> {
+    pub fn user_data(&self) -> u64 {
+        unsafe { jolt_sys::JPC_ShapeSettings_GetUserData(self.as_raw()) }
+    }
+
+    /// Sets the user data given to shapes created from these settings.
+    ///
+    /// Jolt caches the created shape, so this has no effect on shapes created before.
+    pub fn set_user_data(&mut self, user_data: u64) {
+        unsafe { jolt_sys::JPC_ShapeSettings_SetUserData(self.as_raw(), user_data) }
+    }
+
+    pub fn create_shape(&self, runtime: &JoltRuntime) -> crate::Result<Shape> {
         unsafe {
             let shape = jolt_sys::JPC_ShapeSettings_CreateShape(self.as_raw());
             if shape.is_null() {
@@ -23,9 +34,9 @@ impl ShapeSettings {
                 let error = std::ffi::CStr::from_ptr(error_c)
                     .to_string_lossy()
                     .into_owned();
-                Err(error)
+                Err(Error::ShapeCreation(error))
             } else {
-                Ok(Shape::from_raw(shape))
+                Ok(Shape::new(shape, runtime.clone()))
             }
         }
     }
@@ -74,8 +85,8 @@ pub trait HasShapeSettings {
         self.as_shape_settings().clone()
     }
 
-    fn create_shape(&self) -> Result<Shape, String> {
-        self.as_shape_settings().create_shape()
+    fn create_shape(&self, runtime: &JoltRuntime) -> crate::Result<Shape> {
+        self.as_shape_settings().create_shape(runtime)
     }
 }
 
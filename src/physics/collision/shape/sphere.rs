@@ -1,10 +1,10 @@
-use crate::{HasShapeSettings, ShapeSettings};
+use crate::{HasShapeSettings, JoltRuntime, ShapeSettings};
 
 #[repr(transparent)]
 pub struct SphereShapeSettings(ShapeSettings);
 
 impl SphereShapeSettings {
-    pub fn new(radius: f32) -> Self {
+    pub fn new(_runtime: &JoltRuntime, radius: f32) -> Self {
         unsafe {
             Self(ShapeSettings::from_raw(
                 jolt_sys::JPC_SphereShapeSettings_Create(radius) as _,
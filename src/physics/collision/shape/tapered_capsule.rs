@@ -0,0 +1,69 @@
+use crate::{shape::shape_settings::ShapeSettings, HasShapeSettings, JoltRuntime};
+
+/// A capsule with a different radius at the top and bottom, e.g. for character limbs.
+#[repr(transparent)]
+pub struct TaperedCapsuleShapeSettings(ShapeSettings);
+
+impl TaperedCapsuleShapeSettings {
+    pub fn new(
+        _runtime: &JoltRuntime,
+        half_height: f32,
+        top_radius: f32,
+        bottom_radius: f32,
+    ) -> Self {
+        unsafe {
+            Self(ShapeSettings::from_raw(
+                jolt_sys::JPC_TaperedCapsuleShapeSettings_Create(
+                    half_height,
+                    top_radius,
+                    bottom_radius,
+                ) as _,
+            ))
+        }
+    }
+
+    fn as_raw(&self) -> *mut jolt_sys::JPC_TaperedCapsuleShapeSettings {
+        self.0.as_raw() as _
+    }
+
+    /// Half the distance between the centers of the top and bottom spheres.
+    pub fn half_height(&self) -> f32 {
+        unsafe { jolt_sys::JPC_TaperedCapsuleShapeSettings_GetHalfHeight(self.as_raw()) }
+    }
+
+    pub fn set_half_height(&mut self, half_height: f32) {
+        unsafe {
+            jolt_sys::JPC_TaperedCapsuleShapeSettings_SetHalfHeight(self.as_raw(), half_height)
+        }
+    }
+
+    pub fn top_radius(&self) -> f32 {
+        unsafe { jolt_sys::JPC_TaperedCapsuleShapeSettings_GetTopRadius(self.as_raw()) }
+    }
+
+    pub fn set_top_radius(&mut self, top_radius: f32) {
+        unsafe { jolt_sys::JPC_TaperedCapsuleShapeSettings_SetTopRadius(self.as_raw(), top_radius) }
+    }
+
+    pub fn bottom_radius(&self) -> f32 {
+        unsafe { jolt_sys::JPC_TaperedCapsuleShapeSettings_GetBottomRadius(self.as_raw()) }
+    }
+
+    pub fn set_bottom_radius(&mut self, bottom_radius: f32) {
+        unsafe {
+            jolt_sys::JPC_TaperedCapsuleShapeSettings_SetBottomRadius(self.as_raw(), bottom_radius)
+        }
+    }
+}
+
+impl HasShapeSettings for TaperedCapsuleShapeSettings {
+    fn as_shape_settings(&self) -> &ShapeSettings {
+        &self.0
+    }
+}
+
+impl AsRef<ShapeSettings> for TaperedCapsuleShapeSettings {
+    fn as_ref(&self) -> &ShapeSettings {
+        self.as_shape_settings()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{shape::shape_settings::ShapeSettings, HasShapeSettings, JoltRuntime};
+use mint::Vector3;
+
+#[repr(transparent)]
+pub struct TriangleShapeSettings(ShapeSettings);
+
+impl TriangleShapeSettings {
+    pub fn new(
+        _runtime: &JoltRuntime,
+        v1: impl Into<Vector3<f32>>,
+        v2: impl Into<Vector3<f32>>,
+        v3: impl Into<Vector3<f32>>,
+    ) -> Self {
+        unsafe {
+            Self(ShapeSettings::from_raw(
+                jolt_sys::JPC_TriangleShapeSettings_Create(
+                    v1.into().as_ref().as_ptr(),
+                    v2.into().as_ref().as_ptr(),
+                    v3.into().as_ref().as_ptr(),
+                ) as _,
+            ))
+        }
+    }
+
+    fn as_raw(&self) -> *mut jolt_sys::JPC_TriangleShapeSettings {
+        self.0.as_raw() as _
+    }
+
+    pub fn vertices(&self) -> [Vector3<f32>; 3] {
+        let mut vertices = [[0.0; 3]; 3];
+        unsafe {
+            let [v1, v2, v3] = &mut vertices;
+            jolt_sys::JPC_TriangleShapeSettings_GetVertices(
+                self.as_raw(),
+                v1.as_mut_ptr(),
+                v2.as_mut_ptr(),
+                v3.as_mut_ptr(),
+            );
+        }
+        vertices.map(Vector3::from)
+    }
+
+    pub fn set_vertices(
+        &mut self,
+        v1: impl Into<Vector3<f32>>,
+        v2: impl Into<Vector3<f32>>,
+        v3: impl Into<Vector3<f32>>,
+    ) {
+        unsafe {
+            jolt_sys::JPC_TriangleShapeSettings_SetVertices(
+                self.as_raw(),
+                v1.into().as_ref().as_ptr(),
+                v2.into().as_ref().as_ptr(),
+                v3.into().as_ref().as_ptr(),
+            );
+        }
+    }
+
+    pub fn convex_radius(&self) -> f32 {
+        unsafe { jolt_sys::JPC_TriangleShapeSettings_GetConvexRadius(self.as_raw()) }
+    }
+
+    /// Rounds the triangle off, making it thicker by `convex_radius` on all sides.
+    pub fn set_convex_radius(&mut self, convex_radius: f32) {
+        unsafe { jolt_sys::JPC_TriangleShapeSettings_SetConvexRadius(self.as_raw(), convex_radius) }
+    }
+}
+
+impl HasShapeSettings for TriangleShapeSettings {
+    fn as_shape_settings(&self) -> &ShapeSettings {
+        &self.0
+    }
+}
+
+impl AsRef<ShapeSettings> for TriangleShapeSettings {
+    fn as_ref(&self) -> &ShapeSettings {
+        self.as_shape_settings()
+    }
+}
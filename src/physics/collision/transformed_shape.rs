@@ -0,0 +1,29 @@
+use crate::{BodyId, Real, Shape};
+use mint::{Point3, Quaternion, Vector3};
+
+/// A shape together with the world transform and scale of the body it belongs to.
+pub struct TransformedShape {
+    pub shape: Shape,
+    pub position_com: Point3<Real>,
+    pub rotation: Quaternion<f32>,
+    pub scale: Vector3<f32>,
+    pub body_id: BodyId,
+}
+
+impl TransformedShape {
+    /// Takes over the shape reference held by `raw`, returns `None` if it has no shape.
+    pub(crate) unsafe fn from_raw(raw: jolt_sys::JPC_TransformedShape) -> Option<Self> {
+        if raw.shape.is_null() {
+            return None;
+        }
+
+        let [x, y, z, _] = raw.shape_position_com;
+        Some(Self {
+            shape: Shape::from_raw(raw.shape as *mut jolt_sys::JPC_Shape),
+            position_com: Point3 { x, y, z },
+            rotation: Quaternion::from(raw.shape_rotation),
+            scale: Vector3::from(raw.shape_scale),
+            body_id: raw.body_id,
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::impl_enum_try_from;
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConstraintType {
+    Constraint = jolt_sys::JPC_ConstraintType_JPC_CONSTRAINT_TYPE_CONSTRAINT as _,
+    TwoBodyConstraint = jolt_sys::JPC_ConstraintType_JPC_CONSTRAINT_TYPE_TWO_BODY_CONSTRAINT as _,
+}
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConstraintSubType {
+    Fixed = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_FIXED as _,
+    Point = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_POINT as _,
+    Hinge = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_HINGE as _,
+    Slider = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_SLIDER as _,
+    Distance = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_DISTANCE as _,
+    Cone = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_CONE as _,
+    SwingTwist = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_SWING_TWIST as _,
+    SixDof = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_SIX_DOF as _,
+    Path = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_PATH as _,
+    Vehicle = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_VEHICLE as _,
+    RackAndPinion = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_RACK_AND_PINION as _,
+    Gear = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_GEAR as _,
+    Pulley = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_PULLEY as _,
+    User1 = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER1 as _,
+    User2 = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER2 as _,
+    User3 = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER3 as _,
+    User4 = jolt_sys::JPC_ConstraintSubType_JPC_CONSTRAINT_SUB_TYPE_USER4 as _,
+}
+
+impl_enum_try_from!(ConstraintType: jolt_sys::JPC_ConstraintType {
+    Constraint,
+    TwoBodyConstraint,
+});
+
+impl_enum_try_from!(ConstraintSubType: jolt_sys::JPC_ConstraintSubType {
+    Fixed, Point, Hinge, Slider, Distance, Cone, SwingTwist, SixDof, Path, Vehicle,
+    RackAndPinion, Gear, Pulley, User1, User2, User3, User4,
+});
@@ -1,17 +1,29 @@
 pub mod body;
 pub mod body_interface;
+pub mod body_lock;
+pub mod character;
 pub mod collision;
+pub mod constraint;
 pub mod narrow_phase;
+pub mod owned_body;
 pub mod physics_system;
 
 pub use body::*;
 pub use body_interface::*;
+pub use body_lock::*;
+pub use character::*;
 pub use collision::*;
+pub use constraint::*;
+pub use owned_body::*;
 pub use physics_system::*;
 
-#[repr(u8)]
-#[derive(Copy, Clone)]
+use crate::impl_enum_try_from;
+
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Activation {
     Activate = jolt_sys::JPC_Activation_JPC_ACTIVATION_ACTIVATE as _,
     DontActivate = jolt_sys::JPC_Activation_JPC_ACTIVATION_DONT_ACTIVATE as _,
 }
+
+impl_enum_try_from!(Activation: jolt_sys::JPC_Activation { Activate, DontActivate });
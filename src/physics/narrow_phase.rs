@@ -2,13 +2,22 @@ use std::{marker::PhantomData, mem::MaybeUninit};
 
 use mint::{Point3, Vector3};
 
-use crate::Vec3Ext;
+use crate::{RVec3Ext, Real, Vec3Ext};
 
 pub struct Ray {
-    origin: Point3<f32>,
+    origin: Point3<Real>,
     direction: Vector3<f32>,
 }
 
+impl Ray {
+    pub fn new(origin: impl Into<Point3<Real>>, direction: impl Into<Vector3<f32>>) -> Self {
+        Self {
+            origin: origin.into(),
+            direction: direction.into(),
+        }
+    }
+}
+
 pub struct NarrowPhaseQuery<'a>(*const jolt_sys::JPC_NarrowPhaseQuery, PhantomData<&'a ()>);
 
 impl<'a> From<*const jolt_sys::JPC_NarrowPhaseQuery> for NarrowPhaseQuery<'a> {
@@ -17,10 +26,13 @@ impl<'a> From<*const jolt_sys::JPC_NarrowPhaseQuery> for NarrowPhaseQuery<'a> {
     }
 }
 
+unsafe impl Send for NarrowPhaseQuery<'_> {}
+unsafe impl Sync for NarrowPhaseQuery<'_> {}
+
 impl<'a> NarrowPhaseQuery<'a> {
     pub fn cast_ray(&self, ray: &Ray) -> Option<jolt_sys::JPC_RayCastResult> {
         let ray = jolt_sys::JPC_RRayCast {
-            origin: ray.origin.to_fixed_vec3(),
+            origin: ray.origin.to_fixed_rvec3(),
             direction: ray.direction.to_fixed_vec3(),
         };
 
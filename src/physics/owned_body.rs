@@ -0,0 +1,114 @@
+use crate::{BodyId, PhysicsSystem};
+use std::{
+    mem::ManuallyDrop,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// Body interface shared between a [`PhysicsSystem`] and its [`OwnedBody`] handles.
+///
+/// The pointer is set to NULL when the physics system is destroyed (which frees all remaining
+/// bodies). Bodies dropped while the system is stepping are queued up and destroyed once the step
+/// has finished, so a handle can be dropped from anywhere, including listeners.
+pub(crate) struct BodyOwner(Mutex<BodyOwnerState>);
+
+struct BodyOwnerState {
+    body_interface: *mut jolt_sys::JPC_BodyInterface,
+    updating: bool,
+    pending: Vec<BodyId>,
+}
+
+unsafe impl Send for BodyOwner {}
+unsafe impl Sync for BodyOwner {}
+
+impl BodyOwner {
+    pub(crate) fn new(body_interface: *mut jolt_sys::JPC_BodyInterface) -> Self {
+        Self(Mutex::new(BodyOwnerState {
+            body_interface,
+            updating: false,
+            pending: Vec::new(),
+        }))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BodyOwnerState> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Defers the destruction of dropped bodies until [`BodyOwner::end_update`].
+    pub(crate) fn begin_update(&self) {
+        self.lock().updating = true;
+    }
+
+    /// Destroys the bodies that were dropped during the update.
+    pub(crate) fn end_update(&self) {
+        let mut state = self.lock();
+        state.updating = false;
+        for body_id in std::mem::take(&mut state.pending) {
+            unsafe { destroy(state.body_interface, body_id) };
+        }
+    }
+
+    /// Forgets about all bodies, Jolt frees the remaining ones when the system is destroyed.
+    pub(crate) fn release(&self) {
+        let mut state = self.lock();
+        state.body_interface = std::ptr::null_mut();
+        state.pending.clear();
+    }
+}
+
+unsafe fn destroy(body_interface: *mut jolt_sys::JPC_BodyInterface, body_id: BodyId) {
+    if jolt_sys::JPC_BodyInterface_IsAdded(body_interface, body_id) {
+        jolt_sys::JPC_BodyInterface_RemoveBody(body_interface, body_id);
+    }
+    jolt_sys::JPC_BodyInterface_DestroyBody(body_interface, body_id);
+}
+
+/// A body that is removed from its physics system and destroyed when dropped.
+///
+/// Created through [`PhysicsSystem::create_and_add_owned_body`]. Dropping the handle while the
+/// physics system is being updated (e.g. from a listener) keeps the body around until the step has
+/// finished, and dropping it after the physics system is a no-op.
+pub struct OwnedBody {
+    id: BodyId,
+    owner: Arc<BodyOwner>,
+}
+
+impl OwnedBody {
+    /// Takes ownership of a body that was created through a [`BodyInterface`](crate::BodyInterface).
+    ///
+    /// # Safety
+    /// `body_id` must refer to a body in `physics_system` that isn't owned by another handle, and
+    /// it must not be destroyed through any other means.
+    pub unsafe fn from_raw(physics_system: &PhysicsSystem, body_id: BodyId) -> Self {
+        Self {
+            id: body_id,
+            owner: physics_system.body_owner().clone(),
+        }
+    }
+
+    /// Releases ownership of the body, it has to be removed and destroyed manually afterwards.
+    pub fn into_raw(self) -> BodyId {
+        let this = ManuallyDrop::new(self);
+        // Only the shared owner needs releasing, the body itself is kept alive
+        drop(unsafe { std::ptr::read(&this.owner) });
+        this.id
+    }
+
+    pub fn id(&self) -> BodyId {
+        self.id
+    }
+}
+
+impl Drop for OwnedBody {
+    fn drop(&mut self) {
+        let mut state = self.owner.lock();
+        if state.body_interface.is_null() {
+            return;
+        }
+
+        if state.updating {
+            state.pending.push(self.id);
+        } else {
+            unsafe { destroy(state.body_interface, self.id) };
+        }
+    }
+}
@@ -1,19 +1,44 @@
 use crate::{
-    narrow_phase::NarrowPhaseQuery, BodyActivationListener, BodyActivationListenerWrapper,
-    BodyInterface, BroadPhaseLayerInterface, BroadPhaseLayerInterfaceWrapper, ContactListener,
-    ContactListenerWrapper, JobSystem, ObjectLayerPairFilter, ObjectLayerPairFilterWrapper,
-    ObjectVsBroadPhaseLayerFilter, ObjectVsBroadPhaseLayerFilterWrapper, TempAllocator,
+    narrow_phase::NarrowPhaseQuery, Activation, BodyActivationListener,
+    BodyActivationListenerWrapper, BodyCreationSettings, BodyId, BodyInterface, BodyLockInterface,
+    BodyOwner, BroadPhaseLayerInterface, BroadPhaseLayerInterfaceWrapper, CallbackPanic,
+    ContactListener, ContactListenerWrapper, Error, JobSystem, JoltRuntime, ObjectLayerPairFilter,
+    ObjectLayerPairFilterWrapper, ObjectVsBroadPhaseLayerFilter,
+    ObjectVsBroadPhaseLayerFilterWrapper, OwnedBody, Real, TempAllocator,
 };
-use jolt_sys::JPC_EPhysicsUpdateError;
-use mint::Vector3;
-use std::ffi::c_void;
+use bitflags::bitflags;
+use mint::{Point3, Vector3};
+use std::{ffi::c_void, sync::Arc};
+
+bitflags! {
+    /// Limits of the physics system that were hit during an update.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct PhysicsUpdateError: u8 {
+        const MANIFOLD_CACHE_FULL      = jolt_sys::JPC_EPhysicsUpdateError_JPC_PHYSICS_UPDATE_MANIFOLD_CACHE_FULL as u8;
+        const BODY_PAIR_CACHE_FULL     = jolt_sys::JPC_EPhysicsUpdateError_JPC_PHYSICS_UPDATE_BODY_PAIR_CACHE_FULL as u8;
+        const CONTACT_CONSTRAINTS_FULL = jolt_sys::JPC_EPhysicsUpdateError_JPC_PHYSICS_UPDATE_CONTACT_CONSTRAINTS_FULL as u8;
+    }
+}
 
 pub struct PhysicsSystem {
     inner: *mut jolt_sys::JPC_PhysicsSystem,
+    // Jolt only keeps pointers to these, they have to stay alive (and at the same address) for as
+    // long as the system does.
+    _broad_phase_layer_interface: Box<BroadPhaseLayerInterfaceWrapper>,
+    _object_vs_broad_phase_layer_filter: Box<ObjectVsBroadPhaseLayerFilterWrapper>,
+    _object_layer_pair_filter: Box<ObjectLayerPairFilterWrapper>,
+    body_activation_listener: Option<Box<BodyActivationListenerWrapper>>,
+    contact_listener: Option<Box<ContactListenerWrapper>>,
+    // Shared with the wrappers above, re-raised once Jolt has returned control to us.
+    callback_panic: Arc<CallbackPanic>,
+    body_owner: Arc<BodyOwner>,
+    _runtime: JoltRuntime,
 }
 
 impl PhysicsSystem {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
+        runtime: &JoltRuntime,
         max_bodies: u32,
         num_body_mutexes: u32,
         max_body_pairs: u32,
@@ -22,59 +47,117 @@ impl PhysicsSystem {
         object_vs_broad_phase_layer_filter: Box<dyn ObjectVsBroadPhaseLayerFilter>,
         object_layer_pair_filter: Box<dyn ObjectLayerPairFilter>,
     ) -> Self {
-        let broad_phase_layer_wrapper =
-            Box::new(BroadPhaseLayerInterfaceWrapper::new(broad_phase_layer));
-        let object_vs_broad_phase_layer_filter_wrapper = Box::new(
-            ObjectVsBroadPhaseLayerFilterWrapper::new(object_vs_broad_phase_layer_filter),
-        );
-        let object_layer_pair_filter_wrapper =
-            Box::new(ObjectLayerPairFilterWrapper::new(object_layer_pair_filter));
+        let callback_panic = Arc::new(CallbackPanic::default());
+        let broad_phase_layer_wrapper = Box::new(BroadPhaseLayerInterfaceWrapper::new(
+            broad_phase_layer,
+            callback_panic.clone(),
+        ));
+        let object_vs_broad_phase_layer_filter_wrapper =
+            Box::new(ObjectVsBroadPhaseLayerFilterWrapper::new(
+                object_vs_broad_phase_layer_filter,
+                callback_panic.clone(),
+            ));
+        let object_layer_pair_filter_wrapper = Box::new(ObjectLayerPairFilterWrapper::new(
+            object_layer_pair_filter,
+            callback_panic.clone(),
+        ));
+
+        let physics_system = unsafe {
+            let inner = jolt_sys::JPC_PhysicsSystem_Create(
+                max_bodies,
+                num_body_mutexes,
+                max_body_pairs,
+                max_contact_constraints,
+                &*broad_phase_layer_wrapper as *const BroadPhaseLayerInterfaceWrapper
+                    as *const c_void,
+                &*object_vs_broad_phase_layer_filter_wrapper
+                    as *const ObjectVsBroadPhaseLayerFilterWrapper as *const c_void,
+                &*object_layer_pair_filter_wrapper as *const ObjectLayerPairFilterWrapper
+                    as *const c_void,
+            );
 
-        unsafe {
             PhysicsSystem {
-                inner: jolt_sys::JPC_PhysicsSystem_Create(
-                    max_bodies,
-                    num_body_mutexes,
-                    max_body_pairs,
-                    max_contact_constraints,
-                    (Box::into_raw(broad_phase_layer_wrapper)
-                        as *const BroadPhaseLayerInterfaceWrapper)
-                        as *const c_void,
-                    (Box::into_raw(object_vs_broad_phase_layer_filter_wrapper)
-                        as *const ObjectVsBroadPhaseLayerFilterWrapper)
-                        as *const c_void,
-                    (Box::into_raw(object_layer_pair_filter_wrapper)
-                        as *const ObjectLayerPairFilterWrapper)
-                        as *const c_void,
-                ),
+                inner,
+                _broad_phase_layer_interface: broad_phase_layer_wrapper,
+                _object_vs_broad_phase_layer_filter: object_vs_broad_phase_layer_filter_wrapper,
+                _object_layer_pair_filter: object_layer_pair_filter_wrapper,
+                body_activation_listener: None,
+                contact_listener: None,
+                callback_panic,
+                body_owner: Arc::new(BodyOwner::new(
+                    jolt_sys::JPC_PhysicsSystem_GetBodyInterface(inner),
+                )),
+                _runtime: runtime.clone(),
             }
-        }
+        };
+
+        // Jolt queries the broad phase layer interface during creation
+        physics_system.callback_panic.resume();
+        physics_system
     }
 }
 
 impl PhysicsSystem {
+    /// Sets the body activation listener, returning the previous one (if any).
     pub fn set_body_activation_listener(
-        &self,
+        &mut self,
         body_activation_listener: Box<dyn BodyActivationListener>,
-    ) {
+    ) -> Option<Box<dyn BodyActivationListener>> {
+        let wrapper = Box::new(BodyActivationListenerWrapper::new(
+            body_activation_listener,
+            self.callback_panic.clone(),
+        ));
         unsafe {
             jolt_sys::JPC_PhysicsSystem_SetBodyActivationListener(
                 self.inner,
-                Box::into_raw(Box::new(BodyActivationListenerWrapper::new(
-                    body_activation_listener,
-                ))) as *const BodyActivationListenerWrapper as *mut c_void,
+                &*wrapper as *const BodyActivationListenerWrapper as *mut c_void,
             );
         }
+
+        self.body_activation_listener
+            .replace(wrapper)
+            .map(|previous| previous.inner)
+    }
+
+    /// Removes the body activation listener, returning it (if any).
+    pub fn clear_body_activation_listener(&mut self) -> Option<Box<dyn BodyActivationListener>> {
+        unsafe {
+            jolt_sys::JPC_PhysicsSystem_SetBodyActivationListener(self.inner, std::ptr::null_mut());
+        }
+
+        self.body_activation_listener
+            .take()
+            .map(|previous| previous.inner)
     }
 
-    pub fn set_contact_listener(&self, contact_listener: Box<dyn ContactListener>) {
+    /// Sets the contact listener, returning the previous one (if any).
+    pub fn set_contact_listener(
+        &mut self,
+        contact_listener: Box<dyn ContactListener>,
+    ) -> Option<Box<dyn ContactListener>> {
+        let wrapper = Box::new(ContactListenerWrapper::new(
+            contact_listener,
+            self.callback_panic.clone(),
+        ));
         unsafe {
             jolt_sys::JPC_PhysicsSystem_SetContactListener(
                 self.inner,
-                Box::into_raw(Box::new(ContactListenerWrapper::new(contact_listener)))
-                    as *const ContactListenerWrapper as *mut c_void,
+                &*wrapper as *const ContactListenerWrapper as *mut c_void,
             );
         }
+
+        self.contact_listener
+            .replace(wrapper)
+            .map(|previous| previous.inner)
+    }
+
+    /// Removes the contact listener, returning it (if any).
+    pub fn clear_contact_listener(&mut self) -> Option<Box<dyn ContactListener>> {
+        unsafe {
+            jolt_sys::JPC_PhysicsSystem_SetContactListener(self.inner, std::ptr::null_mut());
+        }
+
+        self.contact_listener.take().map(|previous| previous.inner)
     }
 
     pub fn num_bodies(&self) -> u32 {
@@ -89,6 +172,54 @@ impl PhysicsSystem {
         unsafe { jolt_sys::JPC_PhysicsSystem_GetMaxBodies(self.inner) }
     }
 
+    /// Copies the IDs of all bodies into `buffer`, replacing its contents.
+    ///
+    /// The buffer grows to hold [`Self::max_bodies`] IDs the first time, reusing it afterwards
+    /// avoids allocating again.
+    pub fn bodies<'b>(&self, buffer: &'b mut Vec<BodyId>) -> &'b [BodyId] {
+        self.collect_body_ids(buffer, jolt_sys::JPC_PhysicsSystem_GetBodyIDs)
+    }
+
+    /// Copies the IDs of all active bodies into `buffer`, replacing its contents.
+    ///
+    /// See [`Self::bodies`] for how the buffer is sized.
+    pub fn active_bodies<'b>(&self, buffer: &'b mut Vec<BodyId>) -> &'b [BodyId] {
+        self.collect_body_ids(buffer, jolt_sys::JPC_PhysicsSystem_GetActiveBodyIDs)
+    }
+
+    fn collect_body_ids<'b>(
+        &self,
+        buffer: &'b mut Vec<BodyId>,
+        get_body_ids: unsafe extern "C" fn(
+            *const jolt_sys::JPC_PhysicsSystem,
+            u32,
+            *mut u32,
+            *mut jolt_sys::JPC_BodyID,
+        ),
+    ) -> &'b [BodyId] {
+        buffer.clear();
+
+        // Bodies can be added concurrently, so size for the worst case instead of the current count
+        let max_bodies = self.max_bodies();
+        buffer.reserve(max_bodies as usize);
+        if max_bodies == 0 {
+            return buffer;
+        }
+
+        unsafe {
+            let mut num_body_ids = 0;
+            get_body_ids(
+                self.inner,
+                max_bodies,
+                &mut num_body_ids,
+                buffer.as_mut_ptr(),
+            );
+            buffer.set_len(num_body_ids as usize);
+        }
+
+        buffer
+    }
+
     pub fn gravity(&self) -> Vector3<f32> {
         unsafe {
             let mut result = Vector3::from([0.; 3]);
@@ -97,7 +228,7 @@ impl PhysicsSystem {
         }
     }
 
-    pub fn set_gravity<V>(&self, gravity: V)
+    pub fn set_gravity<V>(&mut self, gravity: V)
     where
         V: Into<Vector3<f32>>,
     {
@@ -110,7 +241,7 @@ impl PhysicsSystem {
         BodyInterface::from(unsafe { jolt_sys::JPC_PhysicsSystem_GetBodyInterface(self.inner) })
     }
 
-    pub fn optimize_broad_phase(&self) {
+    pub fn optimize_broad_phase(&mut self) {
         unsafe {
             jolt_sys::JPC_PhysicsSystem_OptimizeBroadPhase(self.inner);
         }
@@ -118,25 +249,147 @@ impl PhysicsSystem {
 
     // TODO: AddStepListener, RemoveStepListener, AddConstraint, RemoveConstraint
 
+    /// Steps the simulation.
+    ///
+    /// # Panics
+    /// If a listener or layer filter panicked while Jolt was calling it, that panic is resumed
+    /// here after the step has finished. This includes callbacks made outside of the step, e.g.
+    /// activation listeners triggered through the body interface.
     pub fn update(
-        &self,
+        &mut self,
         delta_time: f32,
         collision_steps: i32,
         temp_allocator: &mut TempAllocator,
         job_system: &mut JobSystem,
-    ) -> JPC_EPhysicsUpdateError {
-        unsafe {
+    ) -> Result<(), PhysicsUpdateError> {
+        // Owned bodies dropped during the step are destroyed once it has finished
+        self.body_owner.begin_update();
+        temp_allocator.reset_stats();
+        let errors = unsafe {
             jolt_sys::JPC_PhysicsSystem_Update(
                 self.inner,
                 delta_time,
                 collision_steps,
                 temp_allocator.as_ptr(),
                 job_system.as_ptr(),
-            ) as _
+            )
+        };
+        self.body_owner.end_update();
+
+        // Panics from listeners and filters are held back until the step has finished
+        self.callback_panic.resume();
+
+        let errors = PhysicsUpdateError::from_bits_retain(errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
     }
 
-    // TODO: GetBodyLockInterface, GetBodyLockInterfaceNoLock
+    /// Creates and adds a body that is destroyed again when the returned handle is dropped.
+    pub fn create_and_add_owned_body(
+        &self,
+        body_settings: &BodyCreationSettings,
+        activation: Activation,
+    ) -> crate::Result<OwnedBody> {
+        let body_id = self
+            .body_interface()
+            .create_and_add_body(body_settings, activation)?;
+        Ok(unsafe { OwnedBody::from_raw(self, body_id) })
+    }
+
+    /// Overwrites a region of the height field used by `body_id` and wakes the bodies around it,
+    /// see [`HeightFieldShape::set_heights`](crate::HeightFieldShape::set_heights) for the layout
+    /// of `heights`.
+    ///
+    /// # Safety
+    /// The height field is modified in place, it must not be used by another physics system or
+    /// thread while this runs.
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn set_height_field_heights(
+        &mut self,
+        body_id: BodyId,
+        x: u32,
+        z: u32,
+        size_x: u32,
+        size_z: u32,
+        heights: &[f32],
+        temp_allocator: &mut TempAllocator,
+    ) -> crate::Result<()> {
+        let body_interface = self.body_interface();
+        let shape = body_interface
+            .shape(body_id)
+            .ok_or(Error::InvalidBodyId(body_id))?;
+        let height_field = shape.as_height_field().ok_or_else(|| {
+            Error::InvalidHeightField(format!("body {body_id:#x} doesn't use a height field"))
+        })?;
+
+        height_field.set_heights(x, z, size_x, size_z, heights, temp_allocator)?;
+        if size_x == 0 || size_z == 0 {
+            return Ok(());
+        }
+
+        // Height fields have their center of mass at the origin
+        body_interface.notify_shape_changed(body_id, [0.0; 3], false, Activation::DontActivate);
+
+        // Wake everything in the changed region, from the lowest to the highest point of the
+        // height field as the previous heights are gone
+        let (bounds_min, bounds_max) = height_field.local_bounds();
+        let first = height_field.position(x, z);
+        let last = height_field.position(x + size_x - 1, z + size_z - 1);
+        let transform = body_interface.world_transform(body_id);
+        let rotation: [[f32; 3]; 3] = transform.rotation.into();
+        let translation: [Real; 3] = transform.translation.into();
+
+        let mut min = [f32::MAX; 3];
+        let mut max = [f32::MIN; 3];
+        for corner in 0..8 {
+            let local = [
+                if corner & 1 == 0 { first.x } else { last.x },
+                if corner & 2 == 0 {
+                    bounds_min.y
+                } else {
+                    bounds_max.y
+                },
+                if corner & 4 == 0 { first.z } else { last.z },
+            ];
+            for axis in 0..3 {
+                // Real is f64 with double precision
+                #[allow(clippy::unnecessary_cast)]
+                let world = translation[axis] as f32
+                    + rotation[0][axis] * local[0]
+                    + rotation[1][axis] * local[1]
+                    + rotation[2][axis] * local[2];
+                min[axis] = min[axis].min(world);
+                max[axis] = max[axis].max(world);
+            }
+        }
+        body_interface.activate_bodies_in_aabox(Point3::from(min), Point3::from(max));
+
+        Ok(())
+    }
+
+    pub(crate) fn body_owner(&self) -> &Arc<BodyOwner> {
+        &self.body_owner
+    }
+
+    pub fn body_lock_interface<'a>(&'a self) -> BodyLockInterface<'a> {
+        BodyLockInterface::from(unsafe {
+            jolt_sys::JPC_PhysicsSystem_GetBodyLockInterface(self.inner)
+        })
+    }
+
+    /// Like [`PhysicsSystem::body_lock_interface`], but the guards don't take any locks.
+    ///
+    /// # Safety
+    /// No other thread may access the locked bodies while a guard is alive, e.g. through an
+    /// update running on another thread.
+    pub unsafe fn body_lock_interface_no_lock<'a>(&'a self) -> BodyLockInterface<'a> {
+        BodyLockInterface::from(jolt_sys::JPC_PhysicsSystem_GetBodyLockInterfaceNoLock(
+            self.inner,
+        ))
+    }
 
     pub fn narrow_phase_query<'a>(&'a self) -> NarrowPhaseQuery<'a> {
         NarrowPhaseQuery::from(unsafe {
@@ -145,8 +398,18 @@ impl PhysicsSystem {
     }
 }
 
+// Everything that isn't safe to call concurrently with a step (or itself) takes `&mut self`, the
+// remaining accessors go through Jolt's locking interfaces.
+unsafe impl Send for PhysicsSystem {}
+unsafe impl Sync for PhysicsSystem {}
+
 impl Drop for PhysicsSystem {
     fn drop(&mut self) {
+        // Jolt frees any remaining bodies itself, owned bodies must not touch them afterwards
+        self.body_owner.release();
+
+        // The listener and filter wrappers are dropped after this, once Jolt can no longer call
+        // into them.
         unsafe {
             jolt_sys::JPC_PhysicsSystem_Destroy(self.inner);
         }
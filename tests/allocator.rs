@@ -0,0 +1,67 @@
+use jolt::{allocator_stats, Allocator, JoltRuntime, TempAllocator};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+// Sums the sizes passed to the global allocator on the test thread, so a block freed with a
+// different layout than it was allocated with shows up as an imbalance.
+struct CheckedAllocator;
+
+static OUTSTANDING: AtomicIsize = AtomicIsize::new(0);
+
+thread_local! {
+    static TRACKING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for CheckedAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if TRACKING.with(Cell::get) {
+            OUTSTANDING.fetch_add(layout.size() as isize, Ordering::Relaxed);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if TRACKING.with(Cell::get) {
+            OUTSTANDING.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CheckedAllocator = CheckedAllocator;
+
+// Kept as a single test, the allocator can only be registered once per process.
+#[test]
+fn rust_allocator_statistics() {
+    let runtime = JoltRuntime::init_with_allocator(Allocator::Rust {
+        track_statistics: true,
+    });
+    let before = allocator_stats().expect("statistics are tracked");
+    assert!(before.live_allocations > 0);
+    assert!(before.peak_bytes >= before.live_bytes);
+
+    TRACKING.with(|t| t.set(true));
+    let temp_allocator = TempAllocator::create(&runtime, 1 << 20);
+    let during = allocator_stats().unwrap();
+    assert!(OUTSTANDING.load(Ordering::Relaxed) > 1 << 20);
+
+    assert!(during.live_bytes >= before.live_bytes + (1 << 20));
+    assert!(during.peak_bytes >= during.live_bytes);
+    assert!(during.live_allocations > before.live_allocations);
+    assert_eq!(
+        during.total_allocations - before.total_allocations,
+        during.live_allocations - before.live_allocations
+    );
+
+    drop(temp_allocator);
+    TRACKING.with(|t| t.set(false));
+    let after = allocator_stats().unwrap();
+    assert_eq!(OUTSTANDING.load(Ordering::Relaxed), 0);
+
+    assert_eq!(after.live_bytes, before.live_bytes);
+    assert_eq!(after.live_allocations, before.live_allocations);
+    assert_eq!(after.total_allocations, during.total_allocations);
+    assert!(after.peak_bytes >= during.live_bytes);
+}
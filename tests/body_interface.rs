@@ -0,0 +1,128 @@
+use jolt::{BodyId, HasShapeSettings};
+
+struct SingleLayer;
+impl jolt::BroadPhaseLayerInterface for SingleLayer {
+    fn num_broad_phase_layers(&self) -> u32 {
+        1
+    }
+
+    fn broad_phase_layer(&self, _layer: jolt::ObjectLayer) -> jolt::BroadPhaseLayer {
+        0
+    }
+}
+
+impl jolt::ObjectVsBroadPhaseLayerFilter for SingleLayer {
+    fn should_collide(&self, _layer1: jolt::ObjectLayer, _layer2: jolt::BroadPhaseLayer) -> bool {
+        true
+    }
+}
+
+impl jolt::ObjectLayerPairFilter for SingleLayer {
+    fn should_collide(&self, _object1: jolt::ObjectLayer, _object2: jolt::ObjectLayer) -> bool {
+        true
+    }
+}
+
+#[test]
+fn add_and_remove_batches() {
+    let runtime = jolt::JoltRuntime::init();
+    let physics_system = jolt::PhysicsSystem::create(
+        &runtime,
+        64,
+        0,
+        64,
+        64,
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+    );
+
+    let shape = jolt::SphereShapeSettings::new(&runtime, 0.5)
+        .create_shape(&runtime)
+        .expect("Failed to create sphere shape");
+    let body_interface = physics_system.body_interface();
+    let mut bodies: Vec<BodyId> = (0..8).collect();
+    for &body_id in &bodies {
+        let settings = jolt::BodyCreationSettings::new(
+            shape.clone(),
+            [body_id as jolt::Real * 2.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+            jolt::MotionType::Dynamic,
+            0,
+        );
+        body_interface
+            .create_body_with_id(body_id, &settings)
+            .expect("Failed to create body");
+    }
+    let all_added = |bodies: &[BodyId], added: bool| {
+        bodies
+            .iter()
+            .all(|&id| body_interface.is_added(id) == added)
+    };
+
+    // Dropping the prepared batch aborts it
+    let prepared = body_interface.add_bodies_prepare(&mut bodies);
+    drop(prepared);
+    assert!(all_added(&bodies, false));
+
+    body_interface
+        .add_bodies_prepare(&mut bodies)
+        .finalize(jolt::Activation::Activate);
+    assert!(all_added(&bodies, true));
+    assert_eq!(physics_system.num_active_bodies(), 8);
+
+    let (removed, kept) = bodies.split_at_mut(5);
+    body_interface.remove_bodies(removed);
+    assert!(all_added(removed, false));
+    assert!(all_added(kept, true));
+    assert_eq!(physics_system.num_active_bodies(), 3);
+
+    body_interface.remove_bodies(kept);
+    assert!(all_added(&bodies, false));
+    body_interface.destroy_bodies(&bodies);
+    assert_eq!(physics_system.num_bodies(), 0);
+}
+
+#[test]
+fn set_up_body_before_adding() {
+    let runtime = jolt::JoltRuntime::init();
+    let physics_system = jolt::PhysicsSystem::create(
+        &runtime,
+        64,
+        0,
+        64,
+        64,
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+    );
+
+    let settings = jolt::BodyCreationSettings::new(
+        jolt::SphereShapeSettings::new(&runtime, 0.5)
+            .create_shape(&runtime)
+            .expect("Failed to create sphere shape"),
+        [0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+        jolt::MotionType::Dynamic,
+        0,
+    );
+    let body_interface = physics_system.body_interface();
+    let body_id = body_interface
+        .create_body(&settings)
+        .expect("Failed to create body");
+    assert!(!body_interface.is_added(body_id));
+
+    let mut lock = physics_system
+        .body_lock_interface()
+        .lock_write(body_id)
+        .expect("Body ID is invalid");
+    lock.body_mut().set_linear_velocity([1.0, 2.0, 3.0]);
+    drop(lock);
+
+    body_interface.add_body(body_id, jolt::Activation::Activate);
+    assert!(body_interface.is_added(body_id));
+    assert_eq!(
+        body_interface.linear_velocity(body_id),
+        [1.0, 2.0, 3.0].into()
+    );
+}
@@ -0,0 +1,60 @@
+use jolt::{CollisionGroup, GroupFilter, GroupFilterTable, SharedGroupFilter};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[test]
+fn group_filter_table() {
+    let runtime = jolt::JoltRuntime::init();
+
+    let mut table = GroupFilterTable::new(&runtime, 3);
+    table.disable_collision(0, 1);
+    assert!(!table.is_collision_enabled(1, 0));
+    assert!(table.is_collision_enabled(1, 2));
+    assert!(!table.is_collision_enabled(2, 2));
+
+    let filter = SharedGroupFilter::from(table);
+    let limb =
+        |group_id, sub_group_id| CollisionGroup::new(Some(filter.clone()), group_id, sub_group_id);
+
+    assert!(!limb(0, 0).can_collide(&limb(0, 1)));
+    assert!(limb(0, 1).can_collide(&limb(0, 2)));
+    assert!(!limb(0, 2).can_collide(&limb(0, 2)));
+    // Different ragdolls always collide
+    assert!(limb(0, 0).can_collide(&limb(1, 1)));
+    assert!(CollisionGroup::default().can_collide(&CollisionGroup::default()));
+}
+
+struct CountingFilter(Arc<AtomicUsize>);
+
+impl GroupFilter for CountingFilter {
+    fn can_collide(&self, group1: &CollisionGroup, group2: &CollisionGroup) -> bool {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        group1.group_id() != group2.group_id()
+    }
+}
+
+impl Drop for CountingFilter {
+    fn drop(&mut self) {
+        self.0.store(usize::MAX, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn custom_group_filter() {
+    let runtime = jolt::JoltRuntime::init();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let filter = SharedGroupFilter::new(&runtime, CountingFilter(calls.clone()));
+    let a = CollisionGroup::new(Some(filter.clone()), 1, CollisionGroup::INVALID_SUB_GROUP);
+    let b = CollisionGroup::new(None, 2, CollisionGroup::INVALID_SUB_GROUP);
+
+    assert!(a.can_collide(&b));
+    assert!(b.can_collide(&a));
+    assert!(!a.can_collide(&a.clone()));
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+
+    drop((filter, a, b));
+    assert_eq!(calls.load(Ordering::Relaxed), usize::MAX);
+}
@@ -0,0 +1,103 @@
+use jolt::{Error, HasShapeSettings, HeightFieldShapeSettings, PhysicsMaterial};
+
+#[test]
+fn rejects_invalid_grids() {
+    let runtime = jolt::JoltRuntime::init();
+    assert!(matches!(
+        HeightFieldShapeSettings::new(&runtime, &[0.0; 8]),
+        Err(Error::InvalidHeightField(_))
+    ));
+    assert!(matches!(
+        HeightFieldShapeSettings::new(&runtime, &[0.0]),
+        Err(Error::InvalidHeightField(_))
+    ));
+}
+
+#[test]
+fn terrain_with_holes_and_materials() {
+    let runtime = jolt::JoltRuntime::init();
+
+    const SIZE: usize = 8;
+    let mut samples = [0.0; SIZE * SIZE];
+    samples[3 * SIZE + 3] = HeightFieldShapeSettings::NO_COLLISION;
+
+    let mut settings = HeightFieldShapeSettings::new(&runtime, &samples).unwrap();
+    assert_eq!(settings.sample_count(), SIZE as u32);
+    settings.set_scale([2.0, 1.0, 2.0]);
+    assert_eq!(settings.scale(), [2.0, 1.0, 2.0].into());
+
+    let grass = PhysicsMaterial::new(&runtime, "grass", [0, 255, 0, 255]);
+    let rock = PhysicsMaterial::new(&runtime, "rock", [128, 128, 128, 255]);
+    assert_eq!(rock.debug_name(), "rock");
+    assert_eq!(rock.debug_color(), [128, 128, 128, 255]);
+
+    let materials = [grass, rock];
+    let mut indices = [0u8; (SIZE - 1) * (SIZE - 1)];
+    assert!(settings.set_materials(&materials, &[0; 3]).is_err());
+    indices[0] = 2;
+    assert!(settings.set_materials(&materials, &indices).is_err());
+    indices[0] = 1;
+    settings.set_materials(&materials, &indices).unwrap();
+
+    let shape = settings
+        .create_shape(&runtime)
+        .expect("Failed to create height field");
+    assert_eq!(shape.shape_type(), Ok(jolt::ShapeType::HeightField));
+}
+
+#[test]
+fn edit_heights_at_runtime() {
+    let runtime = jolt::JoltRuntime::init();
+    let mut temp_allocator = jolt::TempAllocator::create(&runtime, 1024 * 1024);
+
+    const SIZE: usize = 16;
+    let mut settings = HeightFieldShapeSettings::new(&runtime, &[1.0; SIZE * SIZE]).unwrap();
+    settings.set_block_size(4);
+    // Leave room to edit the flat terrain up to 4.0 later on
+    settings.set_max_height_value(4.0);
+    assert_eq!(settings.max_height_value(), 4.0);
+    assert_eq!(settings.min_height_value(), f32::MAX);
+    let shape = settings
+        .create_shape(&runtime)
+        .expect("Failed to create height field");
+    let height_field = shape
+        .as_height_field()
+        .expect("Shape is not a height field");
+    assert_eq!(height_field.sample_count(), SIZE as u32);
+    assert_eq!(height_field.block_size(), 4);
+    assert!(height_field.min_height_value() <= 1.0);
+    assert!(height_field.max_height_value() >= 4.0);
+
+    let mut heights = [0.0; 4 * 8];
+    height_field.get_heights(4, 8, 4, 8, &mut heights).unwrap();
+    assert!(heights.iter().all(|&h| (h - 1.0).abs() < 0.01));
+
+    // Misaligned, out of range and wrongly sized regions are rejected
+    assert!(height_field.get_heights(1, 0, 4, 8, &mut heights).is_err());
+    assert!(height_field.get_heights(12, 0, 8, 4, &mut heights).is_err());
+    assert!(height_field.get_heights(0, 0, 4, 4, &mut heights).is_err());
+    assert!(height_field
+        .get_heights(0, 0, 2, 16, &mut [0.0; 2 * 16])
+        .is_err());
+    assert!(height_field
+        .get_heights(0, 8, 16, 1, &mut [0.0; SIZE])
+        .is_err());
+    height_field
+        .get_heights(0, 12, 16, 4, &mut [0.0; SIZE * 4])
+        .unwrap();
+
+    let mut new_heights = [2.0; 4 * 8];
+    new_heights[4 * 3 + 1] = HeightFieldShapeSettings::NO_COLLISION;
+    unsafe {
+        height_field
+            .set_heights(4, 8, 4, 8, &new_heights, &mut temp_allocator)
+            .unwrap();
+    }
+
+    height_field.get_heights(4, 8, 4, 8, &mut heights).unwrap();
+    assert_eq!(heights[4 * 3 + 1], HeightFieldShapeSettings::NO_COLLISION);
+    assert!(heights
+        .iter()
+        .filter(|&&h| h != HeightFieldShapeSettings::NO_COLLISION)
+        .all(|&h| (h - 2.0).abs() < 0.01));
+}
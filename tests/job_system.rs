@@ -0,0 +1,134 @@
+use jolt::{HasShapeSettings, Job, JobExecutor, JobSystem, ThreadPoolExecutor};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+struct SingleLayer;
+impl jolt::BroadPhaseLayerInterface for SingleLayer {
+    fn num_broad_phase_layers(&self) -> u32 {
+        1
+    }
+
+    fn broad_phase_layer(&self, _layer: jolt::ObjectLayer) -> jolt::BroadPhaseLayer {
+        0
+    }
+}
+
+impl jolt::ObjectVsBroadPhaseLayerFilter for SingleLayer {
+    fn should_collide(&self, _layer1: jolt::ObjectLayer, _layer2: jolt::BroadPhaseLayer) -> bool {
+        true
+    }
+}
+
+impl jolt::ObjectLayerPairFilter for SingleLayer {
+    fn should_collide(&self, _object1: jolt::ObjectLayer, _object2: jolt::ObjectLayer) -> bool {
+        true
+    }
+}
+
+/// Counts the jobs before handing them to a thread pool.
+struct CountingExecutor {
+    pool: ThreadPoolExecutor,
+    jobs: AtomicUsize,
+}
+
+impl JobExecutor for CountingExecutor {
+    fn max_concurrency(&self) -> usize {
+        self.pool.max_concurrency()
+    }
+
+    fn execute(&self, job: Job) {
+        self.jobs.fetch_add(1, Ordering::Relaxed);
+        self.pool.execute(job);
+    }
+}
+
+/// Never runs anything itself, the thread waiting in `update` has to run every job.
+#[derive(Default)]
+struct HoldingExecutor(Mutex<Vec<Job>>);
+
+impl JobExecutor for HoldingExecutor {
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+
+    fn execute(&self, job: Job) {
+        self.0.lock().unwrap().push(job);
+    }
+}
+
+fn simulate_falling_spheres(runtime: &jolt::JoltRuntime, job_system: &mut JobSystem) {
+    let mut temp_allocator = jolt::TempAllocator::create(runtime, 10 * 1024 * 1024);
+    let mut physics_system = jolt::PhysicsSystem::create(
+        runtime,
+        64,
+        0,
+        64,
+        64,
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+    );
+
+    let shape = jolt::SphereShapeSettings::new(runtime, 0.5)
+        .create_shape(runtime)
+        .expect("Failed to create sphere shape");
+    let body_interface = physics_system.body_interface();
+    let body_ids: Vec<_> = (0..16)
+        .map(|i| {
+            let settings = jolt::BodyCreationSettings::new(
+                shape.clone(),
+                [i as jolt::Real * 2.0, 10.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+                jolt::MotionType::Dynamic,
+                0,
+            );
+            body_interface
+                .create_and_add_body(&settings, jolt::Activation::Activate)
+                .expect("Failed to create body")
+        })
+        .collect();
+
+    for _ in 0..10 {
+        physics_system
+            .update(1. / 60., 1, &mut temp_allocator, job_system)
+            .expect("Physics update failed");
+    }
+
+    let body_interface = physics_system.body_interface();
+    for body_id in body_ids {
+        assert!(body_interface.position(body_id).y < 10.0);
+    }
+}
+
+#[test]
+fn update_with_custom_executor() {
+    let runtime = jolt::JoltRuntime::init();
+    let executor = Arc::new(CountingExecutor {
+        pool: ThreadPoolExecutor::new(3),
+        jobs: AtomicUsize::new(0),
+    });
+    let mut job_system =
+        JobSystem::with_executor(&runtime, JobSystem::MAX_PHYSICS_BARRIERS, executor.clone());
+
+    simulate_falling_spheres(&runtime, &mut job_system);
+    assert!(executor.jobs.load(Ordering::Relaxed) > 0);
+}
+
+#[test]
+fn executor_outlives_job_system() {
+    let runtime = jolt::JoltRuntime::init();
+    let executor = Arc::new(HoldingExecutor::default());
+    let mut job_system =
+        JobSystem::with_executor(&runtime, JobSystem::MAX_PHYSICS_BARRIERS, executor.clone());
+
+    simulate_falling_spheres(&runtime, &mut job_system);
+    drop(job_system);
+
+    let jobs = std::mem::take(&mut *executor.0.lock().unwrap());
+    assert!(!jobs.is_empty());
+    for job in jobs {
+        job.run();
+    }
+}
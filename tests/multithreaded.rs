@@ -0,0 +1,139 @@
+use jolt::HasShapeSettings;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+const OLAYER_NON_MOVING: jolt::ObjectLayer = 0;
+const OLAYER_MOVING: jolt::ObjectLayer = 1;
+
+const BLAYER_NON_MOVING: jolt::BroadPhaseLayer = 0;
+const BLAYER_MOVING: jolt::BroadPhaseLayer = 1;
+
+struct ObjectLayerPairFilterImpl;
+impl jolt::ObjectLayerPairFilter for ObjectLayerPairFilterImpl {
+    fn should_collide(&self, object1: jolt::ObjectLayer, object2: jolt::ObjectLayer) -> bool {
+        object1 == OLAYER_MOVING || object2 == OLAYER_MOVING
+    }
+}
+
+struct ObjectVsBroadPhaseLayerFilterImpl;
+impl jolt::ObjectVsBroadPhaseLayerFilter for ObjectVsBroadPhaseLayerFilterImpl {
+    fn should_collide(&self, layer1: jolt::ObjectLayer, layer2: jolt::BroadPhaseLayer) -> bool {
+        layer1 == OLAYER_MOVING || layer2 == BLAYER_MOVING
+    }
+}
+
+struct BroadPhaseLayerInterfaceImpl;
+impl jolt::BroadPhaseLayerInterface for BroadPhaseLayerInterfaceImpl {
+    fn num_broad_phase_layers(&self) -> u32 {
+        2
+    }
+
+    fn broad_phase_layer(&self, layer: jolt::ObjectLayer) -> jolt::BroadPhaseLayer {
+        match layer {
+            OLAYER_NON_MOVING => BLAYER_NON_MOVING,
+            _ => BLAYER_MOVING,
+        }
+    }
+}
+
+struct CountingActivationListener(Arc<AtomicUsize>);
+impl jolt::BodyActivationListener for CountingActivationListener {
+    fn on_body_activated(&self, _body_id: jolt::BodyId, _user_data: u64) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_body_deactivated(&self, _body_id: jolt::BodyId, _user_data: u64) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn create_bodies_from_multiple_threads() {
+    const NUM_THREADS: usize = 8;
+    const BODIES_PER_THREAD: usize = 64;
+
+    let runtime = jolt::JoltRuntime::init();
+    let mut temp_allocator = jolt::TempAllocator::create(&runtime, 10 * 1024 * 1024);
+    let mut job_system = jolt::JobSystem::create(
+        &runtime,
+        jolt::JobSystem::MAX_PHYSICS_JOBS,
+        jolt::JobSystem::MAX_PHYSICS_BARRIERS,
+        4,
+    );
+
+    let mut physics_system = jolt::PhysicsSystem::create(
+        &runtime,
+        1024,
+        0,
+        1024,
+        1024,
+        Box::new(BroadPhaseLayerInterfaceImpl),
+        Box::new(ObjectVsBroadPhaseLayerFilterImpl),
+        Box::new(ObjectLayerPairFilterImpl),
+    );
+
+    let active_bodies = Arc::new(AtomicUsize::new(0));
+    physics_system
+        .set_body_activation_listener(Box::new(CountingActivationListener(active_bodies.clone())));
+
+    let shape = jolt::SphereShapeSettings::new(&runtime, 0.5)
+        .create_shape(&runtime)
+        .expect("Failed to create sphere shape");
+
+    let body_ids: Vec<jolt::BodyId> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..NUM_THREADS)
+            .map(|thread| {
+                let physics_system = &physics_system;
+                let shape = shape.clone();
+                scope.spawn(move || {
+                    let body_interface = physics_system.body_interface();
+                    (0..BODIES_PER_THREAD)
+                        .map(|i| {
+                            let settings = jolt::BodyCreationSettings::new(
+                                shape.clone(),
+                                [thread as jolt::Real * 2.0, i as jolt::Real * 2.0, 0.0],
+                                [0.0, 0.0, 0.0, 1.0],
+                                jolt::MotionType::Dynamic,
+                                OLAYER_MOVING,
+                            );
+                            body_interface
+                                .create_and_add_body(&settings, jolt::Activation::Activate)
+                                .expect("Failed to create body")
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    let total = NUM_THREADS * BODIES_PER_THREAD;
+    assert_eq!(physics_system.num_bodies() as usize, total);
+    assert_eq!(active_bodies.load(Ordering::Relaxed), total);
+
+    let mut unique_ids = body_ids.clone();
+    unique_ids.sort_unstable();
+    unique_ids.dedup();
+    assert_eq!(unique_ids.len(), total);
+
+    let mut buffer = Vec::new();
+    let mut all_ids = physics_system.bodies(&mut buffer).to_vec();
+    all_ids.sort_unstable();
+    assert_eq!(all_ids, unique_ids);
+    assert_eq!(physics_system.active_bodies(&mut buffer).len(), total);
+
+    physics_system
+        .update(1. / 60., 1, &mut temp_allocator, &mut job_system)
+        .expect("Physics update failed");
+
+    let body_interface = physics_system.body_interface();
+    for body_id in body_ids {
+        assert!(body_interface.is_added(body_id));
+    }
+}
@@ -0,0 +1,140 @@
+use jolt::{HasShapeSettings, JobSystem, OwnedBody, PhysicsSystem, TempAllocator};
+use std::sync::{Arc, Mutex};
+
+struct SingleLayer;
+impl jolt::BroadPhaseLayerInterface for SingleLayer {
+    fn num_broad_phase_layers(&self) -> u32 {
+        1
+    }
+
+    fn broad_phase_layer(&self, _layer: jolt::ObjectLayer) -> jolt::BroadPhaseLayer {
+        0
+    }
+}
+
+impl jolt::ObjectVsBroadPhaseLayerFilter for SingleLayer {
+    fn should_collide(&self, _layer1: jolt::ObjectLayer, _layer2: jolt::BroadPhaseLayer) -> bool {
+        true
+    }
+}
+
+impl jolt::ObjectLayerPairFilter for SingleLayer {
+    fn should_collide(&self, _object1: jolt::ObjectLayer, _object2: jolt::ObjectLayer) -> bool {
+        true
+    }
+}
+
+/// Drops the body it holds as soon as any contact is added.
+struct DropOnContact(Arc<Mutex<Option<OwnedBody>>>);
+
+impl jolt::ContactListener for DropOnContact {
+    fn on_contact_validate(
+        &self,
+        _body1: jolt::BodyRef,
+        _body2: jolt::BodyRef,
+        _base_offset: mint::Vector3<jolt::Real>,
+        _collision_result: &jolt::CollideShapeResult,
+    ) -> jolt::ValidateResult {
+        jolt::ValidateResult::AcceptAllContactsForThisBodyPair
+    }
+
+    fn on_contact_added(
+        &self,
+        _body1: jolt::BodyRef,
+        _body2: jolt::BodyRef,
+        _manifold: &jolt::ContactManifold,
+        _io_settings: &mut jolt::ContactSettings,
+    ) {
+        drop(self.0.lock().unwrap().take());
+    }
+
+    fn on_contact_persisted(
+        &self,
+        _body1: jolt::BodyRef,
+        _body2: jolt::BodyRef,
+        _manifold: &jolt::ContactManifold,
+        _io_settings: &mut jolt::ContactSettings,
+    ) {
+    }
+
+    fn on_contact_removed(&self, _sub_shape_pair: &jolt::SubShapeIDPair) {}
+}
+
+fn create_physics_system(runtime: &jolt::JoltRuntime) -> PhysicsSystem {
+    PhysicsSystem::create(
+        runtime,
+        64,
+        0,
+        64,
+        64,
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+    )
+}
+
+fn create_box(
+    runtime: &jolt::JoltRuntime,
+    physics_system: &PhysicsSystem,
+    y: jolt::Real,
+    motion_type: jolt::MotionType,
+) -> OwnedBody {
+    let shape = jolt::BoxShapeSettings::new(runtime, [0.5, 0.5, 0.5])
+        .create_shape(runtime)
+        .expect("Failed to create box shape");
+    let settings =
+        jolt::BodyCreationSettings::new(shape, [0.0, y, 0.0], [0.0, 0.0, 0.0, 1.0], motion_type, 0);
+    physics_system
+        .create_and_add_owned_body(&settings, jolt::Activation::Activate)
+        .expect("Failed to create body")
+}
+
+#[test]
+fn drop_destroys_body() {
+    let runtime = jolt::JoltRuntime::init();
+    let physics_system = create_physics_system(&runtime);
+
+    let body = create_box(&runtime, &physics_system, 0.0, jolt::MotionType::Dynamic);
+    let body_id = body.id();
+    assert!(physics_system.body_interface().is_added(body_id));
+    assert_eq!(physics_system.num_bodies(), 1);
+
+    drop(body);
+    assert_eq!(physics_system.num_bodies(), 0);
+
+    // Opting out leaves the body alone
+    let body_id = create_box(&runtime, &physics_system, 0.0, jolt::MotionType::Dynamic).into_raw();
+    assert!(physics_system.body_interface().is_added(body_id));
+    drop(unsafe { OwnedBody::from_raw(&physics_system, body_id) });
+    assert_eq!(physics_system.num_bodies(), 0);
+}
+
+#[test]
+fn drop_during_update() {
+    let runtime = jolt::JoltRuntime::init();
+    let mut temp_allocator = TempAllocator::create(&runtime, 10 * 1024 * 1024);
+    let mut job_system = JobSystem::single_threaded(&runtime, JobSystem::MAX_PHYSICS_JOBS);
+    let mut physics_system = create_physics_system(&runtime);
+
+    let _floor = create_box(&runtime, &physics_system, 0.0, jolt::MotionType::Static);
+    let body = create_box(&runtime, &physics_system, 0.9, jolt::MotionType::Dynamic);
+    let held = Arc::new(Mutex::new(Some(body)));
+    physics_system.set_contact_listener(Box::new(DropOnContact(held.clone())));
+    assert_eq!(physics_system.num_bodies(), 2);
+
+    physics_system
+        .update(1. / 60., 1, &mut temp_allocator, &mut job_system)
+        .expect("Physics update failed");
+    assert!(held.lock().unwrap().is_none());
+    assert_eq!(physics_system.num_bodies(), 1);
+}
+
+#[test]
+fn drop_after_physics_system() {
+    let runtime = jolt::JoltRuntime::init();
+    let physics_system = create_physics_system(&runtime);
+
+    let body = create_box(&runtime, &physics_system, 0.0, jolt::MotionType::Dynamic);
+    drop(physics_system);
+    drop(body);
+}
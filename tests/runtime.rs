@@ -0,0 +1,24 @@
+use jolt::{BoxShapeSettings, HasShapeSettings, JobSystem, JoltRuntime, ShapeSubType};
+
+// Kept as a single test, other tests in this binary would hold the runtime alive concurrently.
+#[test]
+fn objects_keep_runtime_alive() {
+    let runtime = JoltRuntime::init();
+    let shape = BoxShapeSettings::new(&runtime, [0.5, 0.5, 0.5])
+        .create_shape(&runtime)
+        .expect("Failed to create box");
+    let job_system = JobSystem::single_threaded(&runtime, JobSystem::MAX_PHYSICS_JOBS);
+
+    drop(runtime);
+    assert!(JoltRuntime::is_initialized());
+    assert_eq!(shape.shape_subtype(), Ok(ShapeSubType::Box));
+
+    let clone = shape.clone();
+    drop(shape);
+    drop(job_system);
+    assert!(JoltRuntime::is_initialized());
+    assert_eq!(clone.shape_subtype(), Ok(ShapeSubType::Box));
+
+    drop(clone);
+    assert!(!JoltRuntime::is_initialized());
+}
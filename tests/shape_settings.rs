@@ -0,0 +1,70 @@
+use jolt::{
+    BoxShapeSettings, HasShapeSettings, OffsetCenterOfMassShapeSettings, ShapeSubType,
+    TaperedCapsuleShapeSettings, TriangleShapeSettings,
+};
+
+#[test]
+fn tapered_capsule() {
+    let runtime = jolt::JoltRuntime::init();
+
+    let mut settings = TaperedCapsuleShapeSettings::new(&runtime, 1.0, 0.25, 0.5);
+    assert_eq!(settings.half_height(), 1.0);
+    assert_eq!(settings.top_radius(), 0.25);
+    assert_eq!(settings.bottom_radius(), 0.5);
+
+    settings.set_half_height(0.5);
+    settings.set_top_radius(0.2);
+    settings.set_bottom_radius(0.3);
+    assert_eq!(settings.half_height(), 0.5);
+    assert_eq!(settings.top_radius(), 0.2);
+    assert_eq!(settings.bottom_radius(), 0.3);
+
+    let shape = settings
+        .create_shape(&runtime)
+        .expect("Failed to create tapered capsule");
+    assert_eq!(shape.shape_subtype(), Ok(ShapeSubType::TaperedCapsule));
+}
+
+#[test]
+fn triangle() {
+    let runtime = jolt::JoltRuntime::init();
+
+    let mut settings =
+        TriangleShapeSettings::new(&runtime, [0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]);
+    assert_eq!(
+        settings.vertices(),
+        [
+            [0.0, 0.0, 0.0].into(),
+            [1.0, 0.0, 0.0].into(),
+            [0.0, 0.0, 1.0].into()
+        ]
+    );
+
+    settings.set_vertices([0.0, 1.0, 0.0], [2.0, 1.0, 0.0], [0.0, 1.0, 2.0]);
+    settings.set_convex_radius(0.05);
+    assert_eq!(settings.vertices()[1], [2.0, 1.0, 0.0].into());
+    assert_eq!(settings.convex_radius(), 0.05);
+
+    let shape = settings
+        .create_shape(&runtime)
+        .expect("Failed to create triangle");
+    assert_eq!(shape.shape_subtype(), Ok(ShapeSubType::Triangle));
+}
+
+#[test]
+fn offset_center_of_mass() {
+    let runtime = jolt::JoltRuntime::init();
+
+    let inner = BoxShapeSettings::new(&runtime, [2.0, 0.5, 1.0]);
+    let mut settings =
+        OffsetCenterOfMassShapeSettings::new(&runtime, inner.as_ref(), [0.0, -0.25, 0.0]);
+    assert_eq!(settings.offset(), [0.0, -0.25, 0.0].into());
+    settings.set_offset([0.0, -0.4, 0.0]);
+    assert_eq!(settings.offset(), [0.0, -0.4, 0.0].into());
+
+    let shape = settings
+        .create_shape(&runtime)
+        .expect("Failed to create offset center of mass shape");
+    assert_eq!(shape.shape_subtype(), Ok(ShapeSubType::OffsetCenterOfMass));
+    assert_eq!(shape.center_of_mass(), [0.0, -0.4, 0.0].into());
+}
@@ -0,0 +1,116 @@
+use jolt::{HasShapeSettings, JobSystem, TempAllocator, TempAllocatorStats};
+
+struct SingleLayer;
+impl jolt::BroadPhaseLayerInterface for SingleLayer {
+    fn num_broad_phase_layers(&self) -> u32 {
+        1
+    }
+
+    fn broad_phase_layer(&self, _layer: jolt::ObjectLayer) -> jolt::BroadPhaseLayer {
+        0
+    }
+}
+
+impl jolt::ObjectVsBroadPhaseLayerFilter for SingleLayer {
+    fn should_collide(&self, _layer1: jolt::ObjectLayer, _layer2: jolt::BroadPhaseLayer) -> bool {
+        true
+    }
+}
+
+impl jolt::ObjectLayerPairFilter for SingleLayer {
+    fn should_collide(&self, _object1: jolt::ObjectLayer, _object2: jolt::ObjectLayer) -> bool {
+        true
+    }
+}
+
+/// Runs `steps` updates of a stack of boxes with `temp_allocator`, returning the stats after each.
+fn simulate(
+    runtime: &jolt::JoltRuntime,
+    temp_allocator: &mut TempAllocator,
+    steps: usize,
+) -> Vec<TempAllocatorStats> {
+    let mut job_system = JobSystem::single_threaded(runtime, JobSystem::MAX_PHYSICS_JOBS);
+    let mut physics_system = jolt::PhysicsSystem::create(
+        runtime,
+        64,
+        0,
+        64,
+        64,
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+        Box::new(SingleLayer),
+    );
+
+    let shape = jolt::BoxShapeSettings::new(runtime, [0.5, 0.5, 0.5])
+        .create_shape(runtime)
+        .expect("Failed to create box shape");
+    let body_interface = physics_system.body_interface();
+    for i in 0..16 {
+        let settings = jolt::BodyCreationSettings::new(
+            shape.clone(),
+            [0.0, i as jolt::Real * 0.9, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+            jolt::MotionType::Dynamic,
+            0,
+        );
+        body_interface
+            .create_and_add_body(&settings, jolt::Activation::Activate)
+            .expect("Failed to create body");
+    }
+
+    (0..steps)
+        .map(|_| {
+            physics_system
+                .update(1. / 60., 1, temp_allocator, &mut job_system)
+                .expect("Physics update failed");
+            temp_allocator.stats()
+        })
+        .collect()
+}
+
+#[test]
+fn fixed_arena() {
+    let runtime = jolt::JoltRuntime::init();
+    let mut temp_allocator = TempAllocator::create(&runtime, 10 * 1024 * 1024);
+    assert_eq!(temp_allocator.stats().capacity, 10 * 1024 * 1024);
+
+    for stats in simulate(&runtime, &mut temp_allocator, 3) {
+        assert_eq!(stats.capacity, 10 * 1024 * 1024);
+        assert_eq!(stats.usage, 0);
+        assert!(stats.high_water_mark > 0);
+        assert_eq!(stats.fallback_allocations, 0);
+    }
+}
+
+#[test]
+fn malloc_fallback() {
+    let runtime = jolt::JoltRuntime::init();
+    let mut temp_allocator = TempAllocator::with_malloc_fallback(&runtime, 0);
+
+    for stats in simulate(&runtime, &mut temp_allocator, 3) {
+        assert_eq!(stats.capacity, 0);
+        assert_eq!(stats.usage, 0);
+        assert!(stats.high_water_mark > 0);
+        assert!(stats.fallback_allocations > 0);
+    }
+}
+
+#[test]
+fn growable_arena() {
+    let runtime = jolt::JoltRuntime::init();
+    let mut temp_allocator = TempAllocator::growable(&runtime, 0);
+
+    let stats = simulate(&runtime, &mut temp_allocator, 3);
+    assert_eq!(stats[0].capacity, 0);
+    assert!(stats[0].high_water_mark > 0);
+    assert!(stats[0].fallback_allocations > 0);
+
+    // The arena grows at the start of the next update
+    assert!(stats[1].capacity >= stats[0].high_water_mark);
+    for stats in &stats[1..] {
+        assert_eq!(stats.usage, 0);
+        if stats.high_water_mark <= stats.capacity {
+            assert_eq!(stats.fallback_allocations, 0);
+        }
+    }
+}
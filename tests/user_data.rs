@@ -0,0 +1,56 @@
+use jolt::{BoxShapeSettings, HasShapeSettings, UserDataKey, UserDataMap};
+
+#[test]
+fn stale_user_data_does_not_resolve() {
+    let mut map = UserDataMap::new();
+    let player = map.insert("player");
+    let crate_ = map.insert("crate");
+    assert_ne!(player.to_user_data(), 0);
+    assert_eq!(map.len(), 2);
+
+    let user_data = player.to_user_data();
+    assert_eq!(UserDataKey::from_user_data(user_data), player);
+    assert_eq!(map.resolve(user_data), Some(&"player"));
+
+    assert_eq!(map.remove(player), Some("player"));
+    assert_eq!(map.remove(player), None);
+
+    // The slot is reused, but the old user data must not point at the new value
+    let barrel = map.insert("barrel");
+    assert_ne!(barrel, player);
+    assert_eq!(map.resolve(user_data), None);
+    assert_eq!(map.resolve(barrel.to_user_data()), Some(&"barrel"));
+    assert_eq!(map.resolve(0), None);
+
+    *map.get_mut(crate_).unwrap() = "box";
+    let mut values: Vec<_> = map.iter().map(|(_, value)| *value).collect();
+    values.sort_unstable();
+    assert_eq!(values, ["barrel", "box"]);
+
+    map.clear();
+    assert!(map.is_empty());
+    assert!(!map.contains(crate_));
+}
+
+#[test]
+fn shape_user_data_round_trip() {
+    let runtime = jolt::JoltRuntime::init();
+    let mut map = UserDataMap::new();
+    let wall = map.insert("wall");
+    let door = map.insert("door");
+
+    let mut settings = BoxShapeSettings::new(&runtime, [1.0, 2.0, 0.1]).to_shape_settings();
+    assert_eq!(settings.user_data(), 0);
+    settings.set_user_data(wall.into());
+    assert_eq!(settings.user_data(), wall.to_user_data());
+
+    let shape = settings
+        .create_shape(&runtime)
+        .expect("Failed to create box");
+    assert_eq!(UserDataKey::from_user_data(shape.user_data()), wall);
+    assert_eq!(map.resolve(shape.user_data()), Some(&"wall"));
+
+    let clone = shape.clone();
+    unsafe { shape.set_user_data(door.into()) };
+    assert_eq!(map.resolve(clone.user_data()), Some(&"door"));
+}